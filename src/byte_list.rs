@@ -0,0 +1,113 @@
+//! Decoder for delimited lists of numeric byte values.
+
+use crate::{
+    decoder::HexDecoderState,
+    error::{CharError, DecodeError, NumberError},
+    macros::impl_decoding_methods,
+};
+
+/// Decoder for delimited lists of numeric byte values, such as C / Rust array literals
+/// (`{0x12, 0x34, 0xAB}`, `[18, 52, 171]`) or dotted decimal notation (`192.168.0.1`).
+///
+/// Each number in the list may be decimal or have a radix prefix: `0x` for hexadecimal,
+/// `0o` for octal, or `0b` for binary numbers. (Unlike in C, a leading zero does not signal
+/// an octal number.) Numbers must fit into `u8`. Numbers are delimited by whitespace and
+/// punctuation chars `,`, `.`, `;`; brackets `[]`, `{}` and `()` are skipped as well.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode, ByteList};
+///
+/// const C_ARRAY: [u8; 3] = ByteList::decode(b"{ 0x12, 0x34, 0xAB }");
+/// assert_eq!(C_ARRAY, [0x12, 0x34, 0xab]);
+/// const DEBUG: &[u8] = &decode!(ByteList, b"[18, 52, 171]");
+/// assert_eq!(DEBUG, C_ARRAY);
+/// const ADDRESS: &[u8] = &decode!(ByteList, b"192.168.0.1");
+/// assert_eq!(ADDRESS, [192, 168, 0, 1]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ByteList;
+
+impl ByteList {
+    const fn is_delimiter(ch: u8) -> bool {
+        ch.is_ascii_whitespace()
+            || matches!(
+                ch,
+                b',' | b'.' | b';' | b'[' | b']' | b'{' | b'}' | b'(' | b')'
+            )
+    }
+
+    /// Parses a number starting at `start`. Returns the number value and the position
+    /// after the number.
+    #[allow(clippy::cast_possible_truncation)] // checked
+    const fn parse_number(input: &[u8], start: usize) -> Result<(u8, usize), DecodeError> {
+        let radix = if input[start] == b'0' && start + 1 < input.len() {
+            match input[start + 1] {
+                b'x' | b'X' => 16,
+                b'o' | b'O' => 8,
+                b'b' | b'B' => 2,
+                _ => 10,
+            }
+        } else {
+            10
+        };
+        let digits_start = if radix == 10 { start } else { start + 2 };
+
+        let mut pos = digits_start;
+        let mut value = 0_u16;
+        while pos < input.len() && !Self::is_delimiter(input[pos]) {
+            let ch = input[pos];
+            if !ch.is_ascii() {
+                let err = CharError::new(ch, None);
+                return Err(DecodeError::invalid_char(err, pos));
+            }
+            let digit = match HexDecoderState::byte_value(ch) {
+                Ok(digit) if digit < radix => digit,
+                _ => {
+                    let err = NumberError::InvalidDigit { digit: ch, radix };
+                    return Err(DecodeError::invalid_number(err, pos));
+                }
+            };
+            value = value * radix as u16 + digit as u16;
+            if value > u8::MAX as u16 {
+                return Err(DecodeError::invalid_number(NumberError::Overflow, start));
+            }
+            pos += 1;
+        }
+
+        if pos == digits_start {
+            Err(DecodeError::invalid_number(NumberError::NoDigits, start))
+        } else {
+            Ok((value as u8, pos))
+        }
+    }
+
+    /// Decodes `input` into `output`.
+    const fn try_decode_into(input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError> {
+        let mut pos = 0;
+        let mut out_len = 0;
+        while pos < input.len() {
+            if Self::is_delimiter(input[pos]) {
+                pos += 1;
+                continue;
+            }
+
+            let (value, next_pos) = match Self::parse_number(input, pos) {
+                Ok(parsed) => parsed,
+                Err(err) => return Err(err),
+            };
+            if out_len < output.len() {
+                output[out_len] = value;
+            }
+            out_len += 1;
+            pos = next_pos;
+        }
+        Ok(out_len)
+    }
+}
+
+impl_decoding_methods!(
+    ByteList,
+    invalid = "contains invalid chars or numbers not fitting into `u8`"
+);
@@ -1,8 +1,18 @@
 //! `Decoder` and closely related types.
 
-use compile_fmt::{clip_ascii, compile_assert, compile_panic, fmt, Ascii};
+use compile_fmt::{clip, compile_assert, compile_panic, fmt, Ascii};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
-use crate::wrappers::{SkipWhitespace, Skipper};
+#[cfg(feature = "zeroize")]
+use crate::secret::{decode_secret, Secret};
+use crate::{
+    error::{CharError, DecodeError, Leftover, TextBuffer, LOCATION_CAPACITY},
+    macros::padding_len,
+    streaming::StreamingDecoder,
+    words::{convert_byte_order, word_count, words_as_bytes_mut, ByteOrder, Word},
+    wrappers::{ConstantTime, SkipWhitespace, Skipper},
+};
 
 // Since `?` is not allowed in `const fn`s, we use its simplified version.
 macro_rules! const_try {
@@ -14,44 +24,6 @@ macro_rules! const_try {
     };
 }
 
-#[derive(Debug)]
-struct DecodeError {
-    invalid_char: u8,
-    // `None` for hex encoding
-    alphabet: Option<Ascii<'static>>,
-}
-
-impl DecodeError {
-    const fn invalid_char(invalid_char: u8, alphabet: Option<Ascii<'static>>) -> Self {
-        Self {
-            invalid_char,
-            alphabet,
-        }
-    }
-
-    const fn panic(self, input_pos: usize) -> ! {
-        if self.invalid_char.is_ascii() {
-            if let Some(alphabet) = self.alphabet {
-                compile_panic!(
-                    "Character '", self.invalid_char as char => fmt::<char>(), "' at position ",
-                    input_pos => fmt::<usize>(), " is not a part of \
-                    the decoder alphabet '", alphabet => clip_ascii(64, ""), "'"
-                );
-            } else {
-                compile_panic!(
-                    "Character '", self.invalid_char as char => fmt::<char>(), "' at position ",
-                    input_pos => fmt::<usize>(), " is not a hex digit"
-                );
-            }
-        } else {
-            compile_panic!(
-                "Non-ASCII character with decimal code ", self.invalid_char => fmt::<u8>(),
-                " encountered at position ", input_pos => fmt::<usize>()
-            );
-        }
-    }
-}
-
 /// Custom encoding scheme based on a certain alphabet (mapping between a subset of ASCII chars
 /// and digits in `0..P`, where `P` is a power of 2).
 ///
@@ -70,17 +42,27 @@ impl DecodeError {
 /// ```
 #[derive(Debug, Clone, Copy)]
 pub struct Encoding {
-    alphabet: Ascii<'static>,
+    alphabet: &'static str,
     table: [u8; 128],
     bits_per_char: u8,
+    lsb_first: bool,
 }
 
 impl Encoding {
     const NO_MAPPING: u8 = u8::MAX;
 
-    const BASE64: Self =
+    /// Hexadecimal encoding with lowercase digits. Unlike [`Decoder::Hex`], decoding
+    /// with this encoding does not accept uppercase digits.
+    pub const HEX: Self = Self::new("0123456789abcdef");
+    /// Base64 encoding with the standard alphabet as per [RFC 3548].
+    ///
+    /// [RFC 3548]: https://datatracker.ietf.org/doc/html/rfc3548.html
+    pub const BASE64: Self =
         Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
-    const BASE64_URL: Self =
+    /// Base64 encoding with the URL-safe alphabet as per [RFC 3548].
+    ///
+    /// [RFC 3548]: https://datatracker.ietf.org/doc/html/rfc3548.html
+    pub const BASE64_URL: Self =
         Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
 
     /// Creates an encoding based on the provided `alphabet`: a sequence of ASCII chars
@@ -107,7 +89,7 @@ impl Encoding {
 
         let mut table = [Self::NO_MAPPING; 128];
         let alphabet_bytes = alphabet.as_bytes();
-        let alphabet = Ascii::new(alphabet); // will panic if `alphabet` contains non-ASCII chars
+        Ascii::new(alphabet); // will panic if `alphabet` contains non-ASCII chars
         let mut index = 0;
         while index < alphabet_bytes.len() {
             let byte = alphabet_bytes[index];
@@ -124,78 +106,193 @@ impl Encoding {
             alphabet,
             table,
             bits_per_char,
+            lsb_first: false,
         }
     }
 
-    const fn lookup(&self, ascii_char: u8) -> Result<u8, DecodeError> {
+    /// Makes this encoding fill bytes starting from the least significant bit, rather than
+    /// from the most significant one. This bit order is used, e.g., by the base64 variant
+    /// in `crypt(3)` password hashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{Decoder, Encoding};
+    /// const CRYPT: Decoder = Decoder::Custom(
+    ///     Encoding::new("./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")
+    ///         .lsb_first(),
+    /// );
+    /// const BYTES: [u8; 3] = CRYPT.decode(b"GEXJ");
+    /// assert_eq!(BYTES, [0x12, 0x34, 0x56]);
+    /// ```
+    #[must_use]
+    pub const fn lsb_first(mut self) -> Self {
+        self.lsb_first = true;
+        self
+    }
+
+    #[cfg(any(feature = "serde", feature = "std"))]
+    pub(crate) const fn alphabet(&self) -> &'static str {
+        self.alphabet
+    }
+
+    #[cfg(any(feature = "serde", feature = "std"))]
+    pub(crate) const fn bits_per_char(&self) -> u8 {
+        self.bits_per_char
+    }
+
+    #[cfg(any(feature = "serde", feature = "std"))]
+    pub(crate) const fn is_lsb_first(&self) -> bool {
+        self.lsb_first
+    }
+
+    const fn invalid_char(&self, ascii_char: u8) -> CharError {
+        CharError::new(ascii_char, Some(self.alphabet))
+    }
+
+    pub(crate) const fn lookup(&self, ascii_char: u8) -> Result<u8, CharError> {
         if !ascii_char.is_ascii() {
-            return Err(DecodeError::invalid_char(ascii_char, Some(self.alphabet)));
+            return Err(self.invalid_char(ascii_char));
         }
         let mapping = self.table[ascii_char as usize];
         if mapping == Self::NO_MAPPING {
-            Err(DecodeError::invalid_char(ascii_char, Some(self.alphabet)))
+            Err(self.invalid_char(ascii_char))
+        } else {
+            Ok(mapping)
+        }
+    }
+
+    /// Constant-time version of [`Self::lookup()`]. Rather than indexing the lookup table
+    /// by `ascii_char`, compares it with all alphabet chars without branching.
+    #[allow(clippy::cast_possible_truncation)] // alphabet length is <= 64
+    pub(crate) const fn lookup_ct(&self, ascii_char: u8) -> Result<u8, CharError> {
+        let alphabet = self.alphabet.as_bytes();
+        let mut mapping = 0_u8;
+        let mut found = 0_u8;
+        let mut index = 0;
+        while index < alphabet.len() {
+            let mask = ct_eq_mask(alphabet[index], ascii_char);
+            mapping |= mask & index as u8;
+            found |= mask;
+            index += 1;
+        }
+
+        if found == 0 {
+            Err(self.invalid_char(ascii_char))
         } else {
             Ok(mapping)
         }
     }
 }
 
+/// Returns `0xff` if `x == y` and 0 otherwise without branching.
+#[allow(clippy::cast_possible_truncation)] // intentional
+const fn ct_eq_mask(x: u8, y: u8) -> u8 {
+    let diff = (x ^ y) as u16;
+    (diff.wrapping_sub(1) >> 8) as u8
+}
+
 /// Internal state of the hexadecimal decoder.
 #[derive(Debug, Clone, Copy)]
-struct HexDecoderState(Option<u8>);
+pub(crate) struct HexDecoderState {
+    digit: Option<u8>,
+    constant_time: bool,
+}
 
 impl HexDecoderState {
-    const fn byte_value(val: u8) -> Result<u8, DecodeError> {
+    pub(crate) const fn byte_value(val: u8) -> Result<u8, CharError> {
         Ok(match val {
             b'0'..=b'9' => val - b'0',
             b'A'..=b'F' => val - b'A' + 10,
             b'a'..=b'f' => val - b'a' + 10,
-            _ => return Err(DecodeError::invalid_char(val, None)),
+            _ => return Err(CharError::new(val, None)),
         })
     }
 
-    const fn new() -> Self {
-        Self(None)
+    /// Constant-time version of [`Self::byte_value()`] using arithmetic range checks.
+    /// Each range check evaluates to -1 (all bits set) if `val` is in the range, and to 0 otherwise.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // checked
+    pub(crate) const fn byte_value_ct(val: u8) -> Result<u8, CharError> {
+        let ch = val as i16;
+        let mut value = -1_i16;
+        // `b'0'..=b'9'` → `ch - b'0' + 1`
+        value += (((0x2f - ch) & (ch - 0x3a)) >> 8) & (ch - 47);
+        // `b'A'..=b'F'` → `ch - b'A' + 11`
+        value += (((0x40 - ch) & (ch - 0x47)) >> 8) & (ch - 54);
+        // `b'a'..=b'f'` → `ch - b'a' + 11`
+        value += (((0x60 - ch) & (ch - 0x67)) >> 8) & (ch - 86);
+
+        if value < 0 {
+            Err(CharError::new(val, None))
+        } else {
+            Ok(value as u8)
+        }
+    }
+
+    pub(crate) const fn new(constant_time: bool) -> Self {
+        Self {
+            digit: None,
+            constant_time,
+        }
     }
 
     #[allow(clippy::option_if_let_else)] // `Option::map_or_else` cannot be used in const fns
-    const fn update(mut self, byte: u8) -> Result<(Self, Option<u8>), DecodeError> {
-        let byte = const_try!(Self::byte_value(byte));
-        let output = if let Some(b) = self.0 {
-            self.0 = None;
+    pub(crate) const fn update(mut self, byte: u8) -> Result<(Self, Option<u8>), CharError> {
+        let byte = if self.constant_time {
+            const_try!(Self::byte_value_ct(byte))
+        } else {
+            const_try!(Self::byte_value(byte))
+        };
+        let output = if let Some(b) = self.digit {
+            self.digit = None;
             Some((b << 4) + byte)
         } else {
-            self.0 = Some(byte);
+            self.digit = Some(byte);
             None
         };
         Ok((self, output))
     }
 
     const fn is_final(self) -> bool {
-        self.0.is_none()
+        self.digit.is_none()
+    }
+
+    #[cfg(feature = "zeroize")]
+    fn wipe(&mut self) {
+        self.digit.zeroize();
     }
 }
 
 /// Internal state of a Base64 decoder.
 #[derive(Debug, Clone, Copy)]
-struct CustomDecoderState {
+pub(crate) struct CustomDecoderState {
     table: Encoding,
     partial_byte: u8,
     filled_bits: u8,
+    constant_time: bool,
 }
 
 impl CustomDecoderState {
-    const fn new(table: Encoding) -> Self {
+    const fn new(table: Encoding, constant_time: bool) -> Self {
         Self {
             table,
             partial_byte: 0,
             filled_bits: 0,
+            constant_time,
         }
     }
 
     #[allow(clippy::comparison_chain)] // not feasible in const context
-    const fn update(mut self, byte: u8) -> Result<(Self, Option<u8>), DecodeError> {
-        let byte = const_try!(self.table.lookup(byte));
+    const fn update(mut self, byte: u8) -> Result<(Self, Option<u8>), CharError> {
+        let byte = if self.constant_time {
+            const_try!(self.table.lookup_ct(byte))
+        } else {
+            const_try!(self.table.lookup(byte))
+        };
+        if self.table.lsb_first {
+            return Ok(self.update_lsb_first(byte));
+        }
+
         let output = if self.filled_bits < 8 - self.table.bits_per_char {
             self.partial_byte = (self.partial_byte << self.table.bits_per_char) + byte;
             self.filled_bits += self.table.bits_per_char;
@@ -216,28 +313,79 @@ impl CustomDecoderState {
         Ok((self, output))
     }
 
+    /// Updates the state with the char `value` for LSB-first encodings.
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    const fn update_lsb_first(mut self, value: u8) -> (Self, Option<u8>) {
+        let acc = (self.partial_byte as u16) | ((value as u16) << self.filled_bits);
+        let filled_bits = self.filled_bits + self.table.bits_per_char;
+        let output = if filled_bits >= 8 {
+            self.partial_byte = (acc >> 8) as u8;
+            self.filled_bits = filled_bits - 8;
+            Some(acc as u8)
+        } else {
+            self.partial_byte = acc as u8;
+            self.filled_bits = filled_bits;
+            None
+        };
+        (self, output)
+    }
+
     const fn is_final(&self) -> bool {
         // We don't check `self.filled_bits` because padding may be implicit
         self.partial_byte == 0
     }
+
+    #[cfg(feature = "zeroize")]
+    fn wipe(&mut self) {
+        self.partial_byte.zeroize();
+        self.filled_bits.zeroize();
+    }
 }
 
 /// State of a decoder.
 #[derive(Debug, Clone, Copy)]
-enum DecoderState {
+pub(crate) enum DecoderState {
     Hex(HexDecoderState),
     Base64(CustomDecoderState),
     Custom(CustomDecoderState),
 }
 
 impl DecoderState {
-    const fn update(self, byte: u8) -> Result<(Self, Option<u8>), DecodeError> {
+    /// State used by the PEM decoder.
+    pub(crate) const fn new_pem() -> Self {
+        Decoder::Base64.new_state(false)
+    }
+
+    /// Number of bits decoded from input chars.
+    pub(crate) const fn bits_per_char(&self) -> usize {
+        match self {
+            Self::Hex(_) => 4,
+            Self::Base64(state) | Self::Custom(state) => state.table.bits_per_char as usize,
+        }
+    }
+
+    /// Number of bits buffered in this state that are not output yet.
+    pub(crate) const fn pending_bits(&self) -> usize {
+        match self {
+            Self::Hex(state) => {
+                if state.digit.is_some() {
+                    4
+                } else {
+                    0
+                }
+            }
+            Self::Base64(state) | Self::Custom(state) => state.filled_bits as usize,
+        }
+    }
+
+    pub(crate) const fn update(self, byte: u8) -> Result<(Self, Option<u8>), CharError> {
         Ok(match self {
             Self::Hex(state) => {
                 let (updated_state, output) = const_try!(state.update(byte));
                 (Self::Hex(updated_state), output)
             }
             Self::Base64(state) => {
+                // Padding is not secret, so branching on it doesn't break constant-time processing.
                 if byte == b'=' {
                     (self, None)
                 } else {
@@ -252,12 +400,342 @@ impl DecoderState {
         })
     }
 
-    const fn is_final(&self) -> bool {
+    pub(crate) const fn is_final(&self) -> bool {
         match self {
             Self::Hex(state) => state.is_final(),
             Self::Base64(state) | Self::Custom(state) => state.is_final(),
         }
     }
+
+    const fn is_constant_time(&self) -> bool {
+        match self {
+            Self::Hex(state) => state.constant_time,
+            Self::Base64(state) | Self::Custom(state) => state.constant_time,
+        }
+    }
+
+    /// Returns an error describing the partially decoded data in this (non-final) state.
+    /// `position` is the number of processed input bytes. For constant-time states, the data
+    /// is not revealed, as with [`Self::secret_leftover_error()`].
+    pub(crate) const fn leftover_error(&self, position: usize) -> DecodeError {
+        if self.is_constant_time() {
+            return self.secret_leftover_error(position);
+        }
+        let leftover = match self {
+            Self::Hex(HexDecoderState {
+                digit: Some(digit), ..
+            }) => Leftover::HexDigit(*digit),
+            Self::Hex(_) => panic!("decoder state is final"),
+            Self::Base64(state) | Self::Custom(state) => Leftover::Bits {
+                value: state.partial_byte,
+                count: state.filled_bits,
+            },
+        };
+        DecodeError::leftover_state(leftover, position)
+    }
+
+    /// Same as [`Self::leftover_error()`], but does not reveal the partially decoded data.
+    #[allow(clippy::cast_possible_truncation)] // pending bits are < 8
+    pub(crate) const fn secret_leftover_error(&self, position: usize) -> DecodeError {
+        let count = self.pending_bits() as u8;
+        DecodeError::leftover_state(Leftover::SecretBits { count }, position)
+    }
+
+    /// Returns an error for the invalid char at `position`. For constant-time states, the char
+    /// is not revealed, as with [`Self::leftover_error()`].
+    pub(crate) const fn char_error(&self, err: CharError, position: usize) -> DecodeError {
+        let err = DecodeError::invalid_char(err, position);
+        if self.is_constant_time() {
+            err.redact_char()
+        } else {
+            err
+        }
+    }
+
+    /// Wipes the partially decoded data from this state.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn wipe(&mut self) {
+        match self {
+            Self::Hex(state) => state.wipe(),
+            Self::Base64(state) | Self::Custom(state) => state.wipe(),
+        }
+    }
+
+    /// Decodes `input` into `bytes` starting from this state. Returns the number of decoded bytes,
+    /// which may exceed the length of `bytes` (the excessive bytes are not written).
+    pub(crate) const fn try_decode_into(
+        &mut self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        bytes: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        match self.try_decode_chunk(input, skipper, bytes, false) {
+            Ok((_, out_len)) => Ok(out_len),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Decodes `input` into `bytes` starting from this state. If `stop_when_full` is set,
+    /// decoding stops once `bytes` is filled. Returns the number of processed input bytes
+    /// and the number of decoded bytes.
+    const fn try_decode_chunk(
+        &mut self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        bytes: &mut [u8],
+        stop_when_full: bool,
+    ) -> Result<(usize, usize), DecodeError> {
+        let mut in_index = 0;
+        let mut out_index = 0;
+
+        while in_index < input.len() {
+            if stop_when_full && out_index == bytes.len() {
+                break;
+            }
+            if let Some(skipper) = skipper {
+                let new_in_index = skipper.skip(input, in_index);
+                if new_in_index != in_index {
+                    in_index = new_in_index;
+                    continue;
+                }
+            }
+
+            let update = match self.update(input[in_index]) {
+                Ok(update) => update,
+                Err(err) => return Err(self.char_error(err, in_index)),
+            };
+            *self = update.0;
+            if let Some(byte) = update.1 {
+                if out_index < bytes.len() {
+                    bytes[out_index] = byte;
+                }
+                out_index += 1;
+            }
+            in_index += 1;
+        }
+        Ok((in_index, out_index))
+    }
+
+    /// Decodes `input` into `bytes` starting from this state.
+    pub(crate) const fn decode_into(
+        mut self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        bytes: &mut [u8],
+    ) {
+        let initial_state = self;
+        match self.try_decode_into(input, skipper, bytes) {
+            Ok(out_len) => check_output_len(
+                out_len,
+                bytes.len(),
+                self.bits_per_char(),
+                self.pending_bits(),
+                Some((initial_state, input, skipper)),
+            ),
+            Err(err) => err.panic_in(input),
+        }
+        if !self.is_final() {
+            self.leftover_error(input.len()).panic();
+        }
+    }
+
+    /// Same as [`Self::decode_into()`], but panic messages do not reveal `input`
+    /// or the partially decoded data since they may be secret.
+    pub(crate) const fn decode_secret_into(
+        mut self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        bytes: &mut [u8],
+    ) {
+        match self.try_decode_into(input, skipper, bytes) {
+            Ok(out_len) => check_output_len(
+                out_len,
+                bytes.len(),
+                self.bits_per_char(),
+                self.pending_bits(),
+                None,
+            ),
+            Err(err) => err.redact_char().panic(),
+        }
+        if !self.is_final() {
+            self.secret_leftover_error(input.len()).panic();
+        }
+    }
+
+    /// Decodes the beginning of `input` into `bytes` starting from this state. Returns the position
+    /// in `input` after the last consumed char, including trailing Base64 padding and `separator`
+    /// (if specified).
+    pub(crate) const fn decode_prefix_into(
+        mut self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        separator: Option<u8>,
+        bytes: &mut [u8],
+    ) -> usize {
+        let (in_index, out_len) = match self.try_decode_chunk(input, skipper, bytes, true) {
+            Ok(lengths) => lengths,
+            Err(err) => err.panic_in(input),
+        };
+        check_output_len(
+            out_len,
+            bytes.len(),
+            self.bits_per_char(),
+            self.pending_bits(),
+            None,
+        );
+
+        let mut end = in_index;
+        if let Self::Base64(_) = self {
+            while end < input.len() && input[end] == b'=' {
+                end += 1;
+            }
+        }
+        let mut next = end;
+        if let Some(skipper) = skipper {
+            while next < input.len() {
+                let new_next = skipper.skip(input, next);
+                if new_next == next {
+                    break;
+                }
+                next = new_next;
+            }
+        }
+        let has_separator = match separator {
+            Some(separator) => next < input.len() && input[next] == separator,
+            None => false,
+        };
+
+        // If the last consumed char is only partially decoded, the prefix must end a group
+        // of chars; otherwise, the following chars would belong to the same group.
+        if self.pending_bits() > 0 && end == in_index && next < input.len() && !has_separator {
+            Self::panic_on_misaligned_prefix(
+                input,
+                bytes.len(),
+                in_index - 1,
+                self.bits_per_char(),
+            );
+        }
+        if !self.is_final() {
+            self.leftover_error(end).panic();
+        }
+
+        match separator {
+            Some(_) if has_separator => next + 1,
+            Some(separator) if next < input.len() => {
+                let location = TextBuffer::location(input, next);
+                compile_panic!(
+                    "Expected separator '", separator as char => fmt::<char>(), "' at position ",
+                    next => fmt::<usize>(), " after the decoded prefix",
+                    location.as_str() => clip(LOCATION_CAPACITY, "")
+                );
+            }
+            _ => end,
+        }
+    }
+
+    const fn panic_on_misaligned_prefix(
+        input: &[u8],
+        prefix_len: usize,
+        char_position: usize,
+        bits_per_char: usize,
+    ) -> ! {
+        // Minimum number of bytes ending on a char boundary, e.g. 3 for Base64.
+        let aligned_len = bits_per_char / (bits_per_char & bits_per_char.wrapping_neg());
+        let location = TextBuffer::location(input, char_position);
+        compile_panic!(
+            "Prefix of ", prefix_len => fmt::<usize>(), " bytes ends in the middle of the char \
+            at position ", char_position => fmt::<usize>(), ". For the encoding with ",
+            bits_per_char => fmt::<usize>(), " bits per char, the prefix length must be \
+            a multiple of ", aligned_len => fmt::<usize>(), " bytes unless the prefix is followed \
+            by padding, a separator or the end of input",
+            location.as_str() => clip(LOCATION_CAPACITY, "")
+        );
+    }
+
+    /// Returns the position of the input char completing the `byte_count`-th output byte
+    /// when decoding starts from this state.
+    const fn output_end(
+        mut self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        byte_count: usize,
+    ) -> usize {
+        let mut in_index = 0;
+        let mut out_count = 0;
+        while in_index < input.len() {
+            if let Some(skipper) = skipper {
+                let new_in_index = skipper.skip(input, in_index);
+                if new_in_index != in_index {
+                    in_index = new_in_index;
+                    continue;
+                }
+            }
+
+            if let Ok((updated_state, output)) = self.update(input[in_index]) {
+                self = updated_state;
+                if output.is_some() {
+                    out_count += 1;
+                    if out_count == byte_count {
+                        return in_index;
+                    }
+                }
+            }
+            in_index += 1;
+        }
+        in_index
+    }
+}
+
+/// Checks that the decoded length `out_len` matches the output buffer length `expected_len`.
+/// `pending_bits` are the bits left in the decoder state after processing the input.
+/// If the initial decoder state and the input are specified, they are used to locate
+/// the end of the last expected output byte on overflow.
+pub(crate) const fn check_output_len(
+    out_len: usize,
+    expected_len: usize,
+    bits_per_char: usize,
+    pending_bits: usize,
+    source: Option<(DecoderState, &[u8], Option<Skipper>)>,
+) {
+    if out_len == expected_len {
+        return;
+    }
+    // Padding and skipped chars (e.g., whitespace) are not counted.
+    let input_chars = (out_len * 8 + pending_bits) / bits_per_char;
+    let expected_chars = (expected_len * 8).div_ceil(bits_per_char);
+
+    if out_len < expected_len {
+        compile_panic!(
+            "Output underflow: the input decodes to ", out_len => fmt::<usize>(),
+            " bytes, while type inference implies ", expected_len => fmt::<usize>(), ". \
+            Either fix the input or change the output buffer length correspondingly. \
+            The input lacks ", expected_chars - input_chars => fmt::<usize>(),
+            " char(s) for the encoding with ", bits_per_char => fmt::<usize>(), " bits per char"
+        );
+    }
+
+    let extra_chars = input_chars - expected_chars;
+    let (initial_state, input, skipper) = match source {
+        Some(source) if expected_len > 0 => source,
+        _ => compile_panic!(
+            "Output overflow: the input decodes to ", out_len => fmt::<usize>(),
+            " bytes, while type inference implies ",  expected_len => fmt::<usize>(), ". \
+            Either fix the input or change the output buffer length correspondingly. \
+            The input has ", extra_chars => fmt::<usize>(),
+            " extra char(s) for the encoding with ", bits_per_char => fmt::<usize>(), " bits per char"
+        ),
+    };
+    let end_position = initial_state.output_end(input, skipper, expected_len);
+    let location = TextBuffer::location(input, end_position);
+    compile_panic!(
+        "Output overflow: the input decodes to ", out_len => fmt::<usize>(),
+        " bytes, while type inference implies ",  expected_len => fmt::<usize>(), ". \
+        Either fix the input or change the output buffer length correspondingly. \
+        The input has ", extra_chars => fmt::<usize>(),
+        " extra char(s) for the encoding with ", bits_per_char => fmt::<usize>(),
+        " bits per char; the last expected output byte ends at position ",
+        end_position => fmt::<usize>(), location.as_str() => clip(LOCATION_CAPACITY, "")
+    );
 }
 
 /// Decoder of a human-friendly encoding, such as hex or base64, into bytes.
@@ -299,12 +777,24 @@ impl Decoder {
         SkipWhitespace(self)
     }
 
-    const fn new_state(self) -> DecoderState {
+    /// Makes this decoder use constant-time processing for input chars, which is useful
+    /// for decoding secrets in runtime.
+    pub const fn constant_time(self) -> ConstantTime {
+        ConstantTime(self)
+    }
+
+    pub(crate) const fn new_state(self, constant_time: bool) -> DecoderState {
         match self {
-            Self::Hex => DecoderState::Hex(HexDecoderState::new()),
-            Self::Base64 => DecoderState::Base64(CustomDecoderState::new(Encoding::BASE64)),
-            Self::Base64Url => DecoderState::Base64(CustomDecoderState::new(Encoding::BASE64_URL)),
-            Self::Custom(encoding) => DecoderState::Custom(CustomDecoderState::new(encoding)),
+            Self::Hex => DecoderState::Hex(HexDecoderState::new(constant_time)),
+            Self::Base64 => {
+                DecoderState::Base64(CustomDecoderState::new(Encoding::BASE64, constant_time))
+            }
+            Self::Base64Url => {
+                DecoderState::Base64(CustomDecoderState::new(Encoding::BASE64_URL, constant_time))
+            }
+            Self::Custom(encoding) => {
+                DecoderState::Custom(CustomDecoderState::new(encoding, constant_time))
+            }
         }
     }
 
@@ -318,16 +808,269 @@ impl Decoder {
         self.do_decode(input, None)
     }
 
+    /// Decodes `input` into a byte array, right-aligning the decoded bytes and filling
+    /// the leading bytes with zeros. This is useful for big-endian integers.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` decodes to more than `N` bytes.
+    /// - Panics if `input` contains invalid chars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const EXPONENT: [u8; 4] = Decoder::Hex.decode_padded(b"010001");
+    /// assert_eq!(EXPONENT, [0, 1, 0, 1]);
+    /// ```
+    pub const fn decode_padded<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        self.do_decode_padded(input, None)
+    }
+
+    /// Decodes exactly `N` bytes from the start of `input`. Returns the decoded bytes
+    /// and the position in `input` after the last consumed char (for Base64, this includes
+    /// padding after the decoded bytes). The rest of the input is not processed, which allows
+    /// parsing concatenated fields.
+    ///
+    /// For encodings with chars not aligned to byte boundaries (e.g., Base64), `N` bytes
+    /// may end in the middle of a char. This is only allowed if the prefix is followed by padding
+    /// or the end of input; e.g., for Base64 without padding, `N` must be a multiple of 3.
+    /// Use [`Self::decode_prefix_until()`] to decode fields delimited by a separator.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` decodes to less than `N` bytes.
+    /// - Panics if the decoded prefix contains invalid chars.
+    /// - Panics if the prefix ends in the middle of a char as described above.
+    /// - Panics if the char completing the `N`-th byte has non-zero trailing bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const INPUT: &[u8] = b"c0ffee:VGVzdA==";
+    /// const HASH: ([u8; 3], usize) = Decoder::Hex.decode_prefix(INPUT);
+    /// assert_eq!(HASH, ([0xc0, 0xff, 0xee], 6));
+    /// // Skip the separator and decode the remaining field.
+    /// const SALT: [u8; 4] = Decoder::Base64.decode(INPUT.split_at(HASH.1 + 1).1);
+    /// assert_eq!(SALT, *b"Test");
+    /// ```
+    pub const fn decode_prefix<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        self.do_decode_prefix(input, None, None)
+    }
+
+    /// Decodes exactly `N` bytes from the start of `input` followed by the `separator` byte
+    /// (e.g., `b':'`). Returns the decoded bytes and the position in `input` after the separator,
+    /// i.e., the start of the next field. If the prefix is followed by the end of input, returns
+    /// the input length.
+    ///
+    /// Unlike with [`Self::decode_prefix()`], the prefix may end in the middle of a char
+    /// if it is followed by the separator, which allows decoding unpadded Base64 fields
+    /// of any length.
+    ///
+    /// # Panics
+    ///
+    /// - Panics in the same situations as [`Self::decode_prefix()`].
+    /// - Panics if the prefix is followed by a char other than `separator`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const INPUT: &[u8] = b"c0ffee:AQAB:VGVzdA";
+    /// const HASH: ([u8; 3], usize) = Decoder::Hex.decode_prefix_until(INPUT, b':');
+    /// assert_eq!(HASH, ([0xc0, 0xff, 0xee], 7));
+    /// const REST: &[u8] = INPUT.split_at(HASH.1).1;
+    /// const EXPONENT: ([u8; 3], usize) = Decoder::Base64.decode_prefix_until(REST, b':');
+    /// assert_eq!(EXPONENT, ([1, 0, 1], 5));
+    /// const SALT: ([u8; 4], usize) =
+    ///     Decoder::Base64.decode_prefix_until(REST.split_at(EXPONENT.1).1, b':');
+    /// assert_eq!(SALT, (*b"Test", 6));
+    /// ```
+    pub const fn decode_prefix_until<const N: usize>(
+        self,
+        input: &[u8],
+        separator: u8,
+    ) -> ([u8; N], usize) {
+        self.do_decode_prefix(input, None, Some(separator))
+    }
+
+    /// Computes the length of the decoded `input` in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is invalid (e.g., contains invalid chars).
+    pub const fn decode_len(&self, input: &[u8]) -> usize {
+        self.do_decode_len(input, None)
+    }
+
+    /// Computes the length of the decoded `input` in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid (e.g., contains invalid chars).
+    pub const fn try_decode_len(&self, input: &[u8]) -> Result<usize, DecodeError> {
+        self.do_try_decode_len(input, None)
+    }
+
+    /// Decodes `input` into the `output` buffer.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `output` length is not equal to the [decoded length](Self::decode_len()) of `input`.
+    /// - Panics if `input` is invalid.
+    pub const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+        self.do_decode_into(input, None, output);
+    }
+
+    /// Creates a [`StreamingDecoder`] for decoding chunked input.
+    pub const fn streaming(self) -> StreamingDecoder {
+        StreamingDecoder::new(self.new_state(false), None)
+    }
+
+    /// Decodes `input` into a byte array wrapped in a [`Secret`], which wipes the decoded bytes
+    /// on drop. If decoding fails midway, the partially decoded data is wiped before panicking.
+    ///
+    /// This method is not constant; it is intended for decoding secrets in runtime.
+    /// Consider using [`ConstantTime::decode_secret()`] to process secrets in constant time.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::decode()`].
+    #[cfg(feature = "zeroize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+    pub fn decode_secret<const N: usize>(self, input: &[u8]) -> Secret<[u8; N]> {
+        decode_secret(self.new_state(false), input, None)
+    }
+
+    /// Decodes `input` into an array of words (unsigned integers) with the specified byte order.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the number of decoded bytes is not a multiple of the word size.
+    /// - Panics in the same situations as [`Self::decode()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use const_decoder::{BigEndian, Decoder};
+    ///
+    /// // Initial hash values for SHA-256.
+    /// const SHA256_IV: [u32; 8] = Decoder::Hex.decode_words::<u32, 8, BigEndian>(
+    ///     b"6a09e667bb67ae853c6ef372a54ff53a510e527f9b05688c1f83d9ab5be0cd19",
+    /// );
+    /// assert_eq!(SHA256_IV[0], 0x6a09_e667);
+    /// ```
+    pub const fn decode_words<W: Word, const N: usize, E: ByteOrder>(self, input: &[u8]) -> [W; N] {
+        self.do_decode_words::<W, N, E>(input, None)
+    }
+
+    /// Decodes `input` into an array of bits. Unlike [`Self::decode()`], the number of decoded bits
+    /// does not need to be a multiple of 8; it is equal to the number of input chars times
+    /// the number of bits per char (e.g., 1 for a base-2 encoding). Bits encoded by each char
+    /// are output starting from the most significant one, or from the least significant one
+    /// for [LSB-first](Encoding::lsb_first()) encodings. For Base64 decoders, padding chars
+    /// are skipped.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not decode to exactly `N` bits.
+    /// - Panics if `input` contains invalid chars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const MASK: [bool; 5] = Decoder::custom("01").decode_bits(b"10110");
+    /// assert_eq!(MASK, [true, false, true, true, false]);
+    /// // Octal-encoded generator polynomial of a BCH code
+    /// const POLY: [bool; 9] = Decoder::custom("01234567").decode_bits(b"721");
+    /// assert_eq!(POLY, [true, true, true, false, true, false, false, false, true]);
+    /// ```
+    pub const fn decode_bits<const N: usize>(self, input: &[u8]) -> [bool; N] {
+        self.do_decode_bits(input, None)
+    }
+
+    /// Decodes `input` into a big-endian `u64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::decode()`]; in particular, if `input` does not
+    /// decode to exactly 8 bytes.
+    pub const fn decode_u64(self, input: &[u8]) -> u64 {
+        u64::from_be_bytes(self.decode(input))
+    }
+
+    /// Decodes `input` into a big-endian `u128`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::decode()`]; in particular, if `input` does not
+    /// decode to exactly 16 bytes.
+    pub const fn decode_u128(self, input: &[u8]) -> u128 {
+        u128::from_be_bytes(self.decode(input))
+    }
+
     pub(crate) const fn do_decode<const N: usize>(
         self,
         input: &[u8],
         skipper: Option<Skipper>,
     ) -> [u8; N] {
         let mut bytes = [0_u8; N];
-        let mut in_index = 0;
-        let mut out_index = 0;
-        let mut state = self.new_state();
+        self.do_decode_into(input, skipper, &mut bytes);
+        bytes
+    }
 
+    pub(crate) const fn do_decode_prefix<const N: usize>(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        separator: Option<u8>,
+    ) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let position = self
+            .new_state(false)
+            .decode_prefix_into(input, skipper, separator, &mut bytes);
+        (bytes, position)
+    }
+
+    pub(crate) const fn do_decode_padded<const N: usize>(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+    ) -> [u8; N] {
+        let padding_len = padding_len(self.do_decode_len(input, skipper), N);
+        let mut bytes = [0_u8; N];
+        let (_, value) = bytes.split_at_mut(padding_len);
+        self.do_decode_into(input, skipper, value);
+        bytes
+    }
+
+    /// Returns the value of an input char, or `None` if the char is padding.
+    const fn char_value(self, ch: u8) -> Result<Option<u8>, CharError> {
+        let value = match self {
+            Self::Hex => HexDecoderState::byte_value(ch),
+            Self::Base64 | Self::Base64Url if ch == b'=' => return Ok(None),
+            Self::Base64 => Encoding::BASE64.lookup(ch),
+            Self::Base64Url => Encoding::BASE64_URL.lookup(ch),
+            Self::Custom(encoding) => encoding.lookup(ch),
+        };
+        match value {
+            Ok(value) => Ok(Some(value)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub(crate) const fn do_decode_bits<const N: usize>(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+    ) -> [bool; N] {
+        let bits_per_char = self.new_state(false).bits_per_char();
+        let lsb_first = matches!(self, Self::Custom(encoding) if encoding.lsb_first);
+        let mut bits = [false; N];
+        let mut bit_len = 0;
+        let mut in_index = 0;
         while in_index < input.len() {
             if let Some(skipper) = skipper {
                 let new_in_index = skipper.skip(input, in_index);
@@ -337,65 +1080,73 @@ impl Decoder {
                 }
             }
 
-            let update = match state.update(input[in_index]) {
-                Ok(update) => update,
-                Err(err) => err.panic(in_index),
+            let value = match self.char_value(input[in_index]) {
+                Ok(value) => value,
+                Err(err) => DecodeError::invalid_char(err, in_index).panic_in(input),
             };
-            state = update.0;
-            if let Some(byte) = update.1 {
-                if out_index < N {
-                    bytes[out_index] = byte;
+            if let Some(value) = value {
+                let mut i = 0;
+                while i < bits_per_char {
+                    let shift = if lsb_first { i } else { bits_per_char - 1 - i };
+                    if bit_len < N {
+                        bits[bit_len] = (value >> shift) & 1 == 1;
+                    }
+                    bit_len += 1;
+                    i += 1;
                 }
-                out_index += 1;
             }
             in_index += 1;
         }
 
         compile_assert!(
-            out_index <= N,
-            "Output overflow: the input decodes to ", out_index => fmt::<usize>(),
-            " bytes, while type inference implies ",  N => fmt::<usize>(), ". \
-            Either fix the input or change the output buffer length correspondingly"
-        );
-        compile_assert!(
-            out_index == N,
-            "Output underflow: the input decodes to ", out_index => fmt::<usize>(),
-            " bytes, while type inference implies ", N => fmt::<usize>(), ". \
+            bit_len == N,
+            "Output length mismatch: the input decodes to ", bit_len => fmt::<usize>(),
+            " bits, while type inference implies ", N => fmt::<usize>(), ". \
             Either fix the input or change the output buffer length correspondingly"
         );
+        bits
+    }
 
-        assert!(
-            state.is_final(),
-            "Left-over state after processing input. This usually means that the input \
-             is incorrect (e.g., an odd number of hex digits)."
-        );
-        bytes
+    pub(crate) const fn do_decode_words<W: Word, const N: usize, E: ByteOrder>(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+    ) -> [W; N] {
+        word_count::<W>(self.do_decode_len(input, skipper));
+        let mut words = [W::ZERO; N];
+        let bytes = words_as_bytes_mut(&mut words);
+        self.do_decode_into(input, skipper, bytes);
+        convert_byte_order::<W, E>(bytes);
+        words
     }
 
-    pub(crate) const fn do_decode_len(self, input: &[u8], skipper: Option<Skipper>) -> usize {
-        let mut in_index = 0;
-        let mut out_index = 0;
-        let mut state = self.new_state();
+    pub(crate) const fn do_decode_into(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        bytes: &mut [u8],
+    ) {
+        self.new_state(false).decode_into(input, skipper, bytes);
+    }
 
-        while in_index < input.len() {
-            if let Some(skipper) = skipper {
-                let new_in_index = skipper.skip(input, in_index);
-                if new_in_index != in_index {
-                    in_index = new_in_index;
-                    continue;
-                }
-            }
+    pub(crate) const fn do_decode_len(self, input: &[u8], skipper: Option<Skipper>) -> usize {
+        match self.do_try_decode_len(input, skipper) {
+            Ok(len) => len,
+            Err(err) => err.panic_in(input),
+        }
+    }
 
-            let update = match state.update(input[in_index]) {
-                Ok(update) => update,
-                Err(err) => err.panic(in_index),
-            };
-            state = update.0;
-            if update.1.is_some() {
-                out_index += 1;
-            }
-            in_index += 1;
+    pub(crate) const fn do_try_decode_len(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+    ) -> Result<usize, DecodeError> {
+        let mut state = self.new_state(false);
+        let len = const_try!(state.try_decode_into(input, skipper, &mut []));
+        if state.is_final() {
+            Ok(len)
+        } else {
+            Err(state.leftover_error(input.len()))
         }
-        out_index
     }
 }
@@ -0,0 +1,527 @@
+//! Constant implementations of cryptographic hash functions, used to pin decoded data.
+
+use compile_fmt::{clip_ascii, compile_panic, Ascii};
+
+/// Loads a `B`-byte block from `data` starting at `offset`.
+const fn load_block<const B: usize>(data: &[u8], offset: usize) -> [u8; B] {
+    let mut block = [0_u8; B];
+    let mut i = 0;
+    while i < B {
+        block[i] = data[offset + i];
+        i += 1;
+    }
+    block
+}
+
+/// Creates padded final blocks for a Merkle–Damgård hash function with `B`-byte blocks
+/// and the big-endian message length (in bits) occupying the last `LEN_BYTES` of the last block.
+/// Returns the blocks and the number of blocks used (1 or 2).
+const fn final_blocks<const B: usize, const LEN_BYTES: usize>(
+    data: &[u8],
+) -> ([[u8; B]; 2], usize) {
+    let mut blocks = [[0_u8; B]; 2];
+    let offset = data.len() - data.len() % B;
+    let remainder = data.len() - offset;
+    let mut i = 0;
+    while i < remainder {
+        blocks[0][i] = data[offset + i];
+        i += 1;
+    }
+    blocks[0][remainder] = 0x80;
+
+    let block_count = if remainder + 1 + LEN_BYTES > B { 2 } else { 1 };
+    let bit_len = (data.len() as u128) * 8;
+    let len_bytes = bit_len.to_be_bytes();
+    let mut i = 0;
+    while i < LEN_BYTES {
+        blocks[block_count - 1][B - LEN_BYTES + i] = len_bytes[16 - LEN_BYTES + i];
+        i += 1;
+    }
+    (blocks, block_count)
+}
+
+const SHA1_INIT: [u32; 5] = [
+    0x6745_2301,
+    0xefcd_ab89,
+    0x98ba_dcfe,
+    0x1032_5476,
+    0xc3d2_e1f0,
+];
+
+#[allow(clippy::many_single_char_names)] // follows the notation from FIPS 180-4
+const fn sha1_compress(state: &mut [u32; 5], block: &[u8; 64]) {
+    let mut w = [0_u32; 80];
+    let mut t = 0;
+    while t < 16 {
+        w[t] = u32::from_be_bytes([
+            block[4 * t],
+            block[4 * t + 1],
+            block[4 * t + 2],
+            block[4 * t + 3],
+        ]);
+        t += 1;
+    }
+    while t < 80 {
+        w[t] = (w[t - 3] ^ w[t - 8] ^ w[t - 14] ^ w[t - 16]).rotate_left(1);
+        t += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+    let mut t = 0;
+    while t < 80 {
+        let (f, k) = match t / 20 {
+            0 => ((b & c) | (!b & d), 0x5a82_7999),
+            1 => (b ^ c ^ d, 0x6ed9_eba1),
+            2 => ((b & c) | (b & d) | (c & d), 0x8f1b_bcdc),
+            _ => (b ^ c ^ d, 0xca62_c1d6),
+        };
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(w[t]);
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+        t += 1;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+}
+
+/// Computes the SHA-1 digest of `data`.
+///
+/// SHA-1 is not collision-resistant; it should only be used to pin data against accidental
+/// changes, or for compatibility with existing fingerprints.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode, sha1, Decoder};
+///
+/// const DIGEST: [u8; 20] = sha1(b"abc");
+/// assert_eq!(DIGEST, decode!(Decoder::Hex, b"a9993e364706816aba3e25717850c26c9cd0d89d"));
+/// ```
+pub const fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut state = SHA1_INIT;
+    let mut offset = 0;
+    while offset + 64 <= data.len() {
+        sha1_compress(&mut state, &load_block(data, offset));
+        offset += 64;
+    }
+    let (blocks, block_count) = final_blocks::<64, 8>(data);
+    let mut i = 0;
+    while i < block_count {
+        sha1_compress(&mut state, &blocks[i]);
+        i += 1;
+    }
+
+    let mut digest = [0_u8; 20];
+    let mut i = 0;
+    while i < 20 {
+        digest[i] = state[i / 4].to_be_bytes()[i % 4];
+        i += 1;
+    }
+    digest
+}
+
+const SHA256_INIT: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+const SHA256_K: [u32; 64] = [
+    0x428a_2f98,
+    0x7137_4491,
+    0xb5c0_fbcf,
+    0xe9b5_dba5,
+    0x3956_c25b,
+    0x59f1_11f1,
+    0x923f_82a4,
+    0xab1c_5ed5,
+    0xd807_aa98,
+    0x1283_5b01,
+    0x2431_85be,
+    0x550c_7dc3,
+    0x72be_5d74,
+    0x80de_b1fe,
+    0x9bdc_06a7,
+    0xc19b_f174,
+    0xe49b_69c1,
+    0xefbe_4786,
+    0x0fc1_9dc6,
+    0x240c_a1cc,
+    0x2de9_2c6f,
+    0x4a74_84aa,
+    0x5cb0_a9dc,
+    0x76f9_88da,
+    0x983e_5152,
+    0xa831_c66d,
+    0xb003_27c8,
+    0xbf59_7fc7,
+    0xc6e0_0bf3,
+    0xd5a7_9147,
+    0x06ca_6351,
+    0x1429_2967,
+    0x27b7_0a85,
+    0x2e1b_2138,
+    0x4d2c_6dfc,
+    0x5338_0d13,
+    0x650a_7354,
+    0x766a_0abb,
+    0x81c2_c92e,
+    0x9272_2c85,
+    0xa2bf_e8a1,
+    0xa81a_664b,
+    0xc24b_8b70,
+    0xc76c_51a3,
+    0xd192_e819,
+    0xd699_0624,
+    0xf40e_3585,
+    0x106a_a070,
+    0x19a4_c116,
+    0x1e37_6c08,
+    0x2748_774c,
+    0x34b0_bcb5,
+    0x391c_0cb3,
+    0x4ed8_aa4a,
+    0x5b9c_ca4f,
+    0x682e_6ff3,
+    0x748f_82ee,
+    0x78a5_636f,
+    0x84c8_7814,
+    0x8cc7_0208,
+    0x90be_fffa,
+    0xa450_6ceb,
+    0xbef9_a3f7,
+    0xc671_78f2,
+];
+
+#[allow(clippy::many_single_char_names)] // follows the notation from FIPS 180-4
+const fn sha256_compress(state: &mut [u32; 8], block: &[u8; 64]) {
+    let mut w = [0_u32; 64];
+    let mut t = 0;
+    while t < 16 {
+        w[t] = u32::from_be_bytes([
+            block[4 * t],
+            block[4 * t + 1],
+            block[4 * t + 2],
+            block[4 * t + 3],
+        ]);
+        t += 1;
+    }
+    while t < 64 {
+        let s0 = w[t - 15].rotate_right(7) ^ w[t - 15].rotate_right(18) ^ (w[t - 15] >> 3);
+        let s1 = w[t - 2].rotate_right(17) ^ w[t - 2].rotate_right(19) ^ (w[t - 2] >> 10);
+        w[t] = w[t - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[t - 7])
+            .wrapping_add(s1);
+        t += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    let mut t = 0;
+    while t < 64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(SHA256_K[t])
+            .wrapping_add(w[t]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+        t += 1;
+    }
+
+    let updates = [a, b, c, d, e, f, g, h];
+    let mut i = 0;
+    while i < 8 {
+        state[i] = state[i].wrapping_add(updates[i]);
+        i += 1;
+    }
+}
+
+/// Computes the SHA-256 digest of `data`.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode, sha256, Decoder};
+///
+/// const DIGEST: [u8; 32] = sha256(b"abc");
+/// assert_eq!(
+///     DIGEST,
+///     decode!(
+///         Decoder::Hex,
+///         b"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+///     )
+/// );
+/// ```
+pub const fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state = SHA256_INIT;
+    let mut offset = 0;
+    while offset + 64 <= data.len() {
+        sha256_compress(&mut state, &load_block(data, offset));
+        offset += 64;
+    }
+    let (blocks, block_count) = final_blocks::<64, 8>(data);
+    let mut i = 0;
+    while i < block_count {
+        sha256_compress(&mut state, &blocks[i]);
+        i += 1;
+    }
+
+    let mut digest = [0_u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        digest[i] = state[i / 4].to_be_bytes()[i % 4];
+        i += 1;
+    }
+    digest
+}
+
+const SHA512_INIT: [u64; 8] = [
+    0x6a09_e667_f3bc_c908,
+    0xbb67_ae85_84ca_a73b,
+    0x3c6e_f372_fe94_f82b,
+    0xa54f_f53a_5f1d_36f1,
+    0x510e_527f_ade6_82d1,
+    0x9b05_688c_2b3e_6c1f,
+    0x1f83_d9ab_fb41_bd6b,
+    0x5be0_cd19_137e_2179,
+];
+
+const SHA512_K: [u64; 80] = [
+    0x428a_2f98_d728_ae22,
+    0x7137_4491_23ef_65cd,
+    0xb5c0_fbcf_ec4d_3b2f,
+    0xe9b5_dba5_8189_dbbc,
+    0x3956_c25b_f348_b538,
+    0x59f1_11f1_b605_d019,
+    0x923f_82a4_af19_4f9b,
+    0xab1c_5ed5_da6d_8118,
+    0xd807_aa98_a303_0242,
+    0x1283_5b01_4570_6fbe,
+    0x2431_85be_4ee4_b28c,
+    0x550c_7dc3_d5ff_b4e2,
+    0x72be_5d74_f27b_896f,
+    0x80de_b1fe_3b16_96b1,
+    0x9bdc_06a7_25c7_1235,
+    0xc19b_f174_cf69_2694,
+    0xe49b_69c1_9ef1_4ad2,
+    0xefbe_4786_384f_25e3,
+    0x0fc1_9dc6_8b8c_d5b5,
+    0x240c_a1cc_77ac_9c65,
+    0x2de9_2c6f_592b_0275,
+    0x4a74_84aa_6ea6_e483,
+    0x5cb0_a9dc_bd41_fbd4,
+    0x76f9_88da_8311_53b5,
+    0x983e_5152_ee66_dfab,
+    0xa831_c66d_2db4_3210,
+    0xb003_27c8_98fb_213f,
+    0xbf59_7fc7_beef_0ee4,
+    0xc6e0_0bf3_3da8_8fc2,
+    0xd5a7_9147_930a_a725,
+    0x06ca_6351_e003_826f,
+    0x1429_2967_0a0e_6e70,
+    0x27b7_0a85_46d2_2ffc,
+    0x2e1b_2138_5c26_c926,
+    0x4d2c_6dfc_5ac4_2aed,
+    0x5338_0d13_9d95_b3df,
+    0x650a_7354_8baf_63de,
+    0x766a_0abb_3c77_b2a8,
+    0x81c2_c92e_47ed_aee6,
+    0x9272_2c85_1482_353b,
+    0xa2bf_e8a1_4cf1_0364,
+    0xa81a_664b_bc42_3001,
+    0xc24b_8b70_d0f8_9791,
+    0xc76c_51a3_0654_be30,
+    0xd192_e819_d6ef_5218,
+    0xd699_0624_5565_a910,
+    0xf40e_3585_5771_202a,
+    0x106a_a070_32bb_d1b8,
+    0x19a4_c116_b8d2_d0c8,
+    0x1e37_6c08_5141_ab53,
+    0x2748_774c_df8e_eb99,
+    0x34b0_bcb5_e19b_48a8,
+    0x391c_0cb3_c5c9_5a63,
+    0x4ed8_aa4a_e341_8acb,
+    0x5b9c_ca4f_7763_e373,
+    0x682e_6ff3_d6b2_b8a3,
+    0x748f_82ee_5def_b2fc,
+    0x78a5_636f_4317_2f60,
+    0x84c8_7814_a1f0_ab72,
+    0x8cc7_0208_1a64_39ec,
+    0x90be_fffa_2363_1e28,
+    0xa450_6ceb_de82_bde9,
+    0xbef9_a3f7_b2c6_7915,
+    0xc671_78f2_e372_532b,
+    0xca27_3ece_ea26_619c,
+    0xd186_b8c7_21c0_c207,
+    0xeada_7dd6_cde0_eb1e,
+    0xf57d_4f7f_ee6e_d178,
+    0x06f0_67aa_7217_6fba,
+    0x0a63_7dc5_a2c8_98a6,
+    0x113f_9804_bef9_0dae,
+    0x1b71_0b35_131c_471b,
+    0x28db_77f5_2304_7d84,
+    0x32ca_ab7b_40c7_2493,
+    0x3c9e_be0a_15c9_bebc,
+    0x431d_67c4_9c10_0d4c,
+    0x4cc5_d4be_cb3e_42b6,
+    0x597f_299c_fc65_7e2a,
+    0x5fcb_6fab_3ad6_faec,
+    0x6c44_198c_4a47_5817,
+];
+
+#[allow(clippy::many_single_char_names)] // follows the notation from FIPS 180-4
+const fn sha512_compress(state: &mut [u64; 8], block: &[u8; 128]) {
+    let mut w = [0_u64; 80];
+    let mut t = 0;
+    while t < 16 {
+        w[t] = u64::from_be_bytes(load_block(block, 8 * t));
+        t += 1;
+    }
+    while t < 80 {
+        let s0 = w[t - 15].rotate_right(1) ^ w[t - 15].rotate_right(8) ^ (w[t - 15] >> 7);
+        let s1 = w[t - 2].rotate_right(19) ^ w[t - 2].rotate_right(61) ^ (w[t - 2] >> 6);
+        w[t] = w[t - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[t - 7])
+            .wrapping_add(s1);
+        t += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    let mut t = 0;
+    while t < 80 {
+        let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(SHA512_K[t])
+            .wrapping_add(w[t]);
+        let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+        t += 1;
+    }
+
+    let updates = [a, b, c, d, e, f, g, h];
+    let mut i = 0;
+    while i < 8 {
+        state[i] = state[i].wrapping_add(updates[i]);
+        i += 1;
+    }
+}
+
+/// Computes the SHA-512 digest of `data`.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode, sha512, Decoder};
+///
+/// const DIGEST: [u8; 64] = sha512(b"abc");
+/// assert_eq!(
+///     DIGEST,
+///     decode!(
+///         Decoder::Hex,
+///         b"ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+///           2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+///     )
+/// );
+/// ```
+pub const fn sha512(data: &[u8]) -> [u8; 64] {
+    let mut state = SHA512_INIT;
+    let mut offset = 0;
+    while offset + 128 <= data.len() {
+        sha512_compress(&mut state, &load_block(data, offset));
+        offset += 128;
+    }
+    let (blocks, block_count) = final_blocks::<128, 16>(data);
+    let mut i = 0;
+    while i < block_count {
+        sha512_compress(&mut state, &blocks[i]);
+        i += 1;
+    }
+
+    let mut digest = [0_u8; 64];
+    let mut i = 0;
+    while i < 64 {
+        digest[i] = state[i / 8].to_be_bytes()[i % 8];
+        i += 1;
+    }
+    digest
+}
+
+/// Writes the hex encoding of `bytes` into `buffer` and returns it as a string.
+const fn hex_str<'a>(bytes: &[u8], buffer: &'a mut [u8; 128]) -> &'a str {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut i = 0;
+    while i < bytes.len() && 2 * i < buffer.len() {
+        buffer[2 * i] = HEX_DIGITS[(bytes[i] >> 4) as usize];
+        buffer[2 * i + 1] = HEX_DIGITS[(bytes[i] & 0xf) as usize];
+        i += 1;
+    }
+    let (hex, _) = buffer.split_at(2 * i);
+    match core::str::from_utf8(hex) {
+        Ok(s) => s,
+        Err(_) => unreachable!(),
+    }
+}
+
+#[doc(hidden)] // implementation detail of the `decode!` macro
+pub const fn assert_digest(actual: &[u8], expected: &[u8]) {
+    let mut is_match = actual.len() == expected.len();
+    let mut i = 0;
+    while is_match && i < actual.len() {
+        is_match = actual[i] == expected[i];
+        i += 1;
+    }
+
+    if !is_match {
+        let mut actual_buffer = [0_u8; 128];
+        let actual = Ascii::new(hex_str(actual, &mut actual_buffer));
+        let mut expected_buffer = [0_u8; 128];
+        let expected = Ascii::new(hex_str(expected, &mut expected_buffer));
+        compile_panic!(
+            "Digest mismatch for the decoded data: expected ", expected => clip_ascii(128, ""),
+            ", got ", actual => clip_ascii(128, "")
+        );
+    }
+}
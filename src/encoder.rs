@@ -0,0 +1,95 @@
+//! Encoding bytes using an `Encoding` alphabet.
+
+use crate::decoder::Encoding;
+
+/// Incremental encoder state.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EncoderState {
+    encoding: Encoding,
+    buffer: u16,
+    buffered_bits: u8,
+    char_count: usize,
+}
+
+impl EncoderState {
+    pub(crate) const fn new(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            buffer: 0,
+            buffered_bits: 0,
+            char_count: 0,
+        }
+    }
+
+    fn output_char<E>(
+        &mut self,
+        index: u16,
+        output: &mut impl FnMut(u8) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let alphabet = self.encoding.alphabet().as_bytes();
+        self.char_count += 1;
+        output(alphabet[usize::from(index)])
+    }
+
+    /// Encodes the next `byte`, supplying the encoded chars to `output`.
+    pub(crate) fn update<E>(
+        &mut self,
+        byte: u8,
+        mut output: impl FnMut(u8) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let bits_per_char = self.encoding.bits_per_char();
+        let mask = (1_u16 << bits_per_char) - 1;
+
+        if self.encoding.is_lsb_first() {
+            self.buffer |= u16::from(byte) << self.buffered_bits;
+            self.buffered_bits += 8;
+            while self.buffered_bits >= bits_per_char {
+                self.buffered_bits -= bits_per_char;
+                let index = self.buffer & mask;
+                self.buffer >>= bits_per_char;
+                self.output_char(index, &mut output)?;
+            }
+            return Ok(());
+        }
+
+        self.buffer = (self.buffer << 8) | u16::from(byte);
+        self.buffered_bits += 8;
+        while self.buffered_bits >= bits_per_char {
+            self.buffered_bits -= bits_per_char;
+            let index = (self.buffer >> self.buffered_bits) & mask;
+            self.output_char(index, &mut output)?;
+        }
+        self.buffer &= (1 << self.buffered_bits) - 1;
+        Ok(())
+    }
+
+    /// Outputs the remaining buffered bits (padded with zeros) and, if `padding` is set,
+    /// `=` padding chars so that the number of output chars is a multiple of the block size
+    /// (e.g., 4 chars for base64).
+    pub(crate) fn finish<E>(
+        mut self,
+        padding: bool,
+        mut output: impl FnMut(u8) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let bits_per_char = self.encoding.bits_per_char();
+        if self.buffered_bits > 0 {
+            let mask = (1_u16 << bits_per_char) - 1;
+            let index = if self.encoding.is_lsb_first() {
+                self.buffer & mask
+            } else {
+                (self.buffer << (bits_per_char - self.buffered_bits)) & mask
+            };
+            self.output_char(index, &mut output)?;
+        }
+
+        if padding {
+            // Number of chars encoding a whole number of bytes.
+            let block_len = 8 >> bits_per_char.trailing_zeros().min(3);
+            while self.char_count % block_len != 0 {
+                output(b'=')?;
+                self.char_count += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,696 @@
+//! Decoding errors.
+
+use core::fmt;
+
+use compile_fmt::{clip, clip_ascii, compile_panic, fmt, Ascii};
+
+/// Invalid char encountered during decoding.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CharError {
+    invalid_char: u8,
+    // `None` for hex encoding
+    alphabet: Option<&'static str>,
+}
+
+impl CharError {
+    pub(crate) const fn new(invalid_char: u8, alphabet: Option<&'static str>) -> Self {
+        Self {
+            invalid_char,
+            alphabet,
+        }
+    }
+}
+
+/// Maximum number of input bytes shown on each side of the offending char in panic messages.
+const EXCERPT_RADIUS: usize = 24;
+/// Capacity sufficient for the location description with the maximum-length excerpt.
+pub(crate) const LOCATION_CAPACITY: usize = 192;
+
+/// Fixed-capacity ASCII string buffer for composing parts of panic messages in compile time.
+pub(crate) struct TextBuffer<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> TextBuffer<N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            len: 0,
+        }
+    }
+
+    const fn push(&mut self, byte: u8) {
+        self.buffer[self.len] = byte;
+        self.len += 1;
+    }
+
+    const fn push_str(&mut self, s: &str) {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // remainder is < 10
+    const fn push_usize(&mut self, mut value: usize) {
+        let mut digits = [0_u8; 20];
+        let mut digit_count = 0;
+        loop {
+            digits[digit_count] = b'0' + (value % 10) as u8;
+            digit_count += 1;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        while digit_count > 0 {
+            digit_count -= 1;
+            self.push(digits[digit_count]);
+        }
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buffer.split_at(self.len);
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => panic!("text is not ASCII"),
+        }
+    }
+}
+
+impl TextBuffer<LOCATION_CAPACITY> {
+    /// Creates a human-readable location of the char at `position` in the input: line, column
+    /// and an excerpt of the surrounding line with a marker pointing at the char.
+    pub(crate) const fn location(input: &[u8], position: usize) -> Self {
+        let mut line = 1;
+        let mut line_start = 0;
+        let mut i = 0;
+        while i < position && i < input.len() {
+            if input[i] == b'\n' {
+                line += 1;
+                line_start = i + 1;
+            }
+            i += 1;
+        }
+        let mut line_end = line_start;
+        while line_end < input.len() && input[line_end] != b'\n' {
+            line_end += 1;
+        }
+        // Exclude the `\r` of a CRLF line break unless it is the offending char.
+        if line_end > position + 1 && input[line_end - 1] == b'\r' {
+            line_end -= 1;
+        }
+
+        let excerpt_start = if position - line_start > EXCERPT_RADIUS {
+            position - EXCERPT_RADIUS
+        } else {
+            line_start
+        };
+        let excerpt_end = if line_end - position > EXCERPT_RADIUS + 1 {
+            position + EXCERPT_RADIUS + 1
+        } else {
+            line_end
+        };
+
+        let mut this = Self::new();
+        this.push_str(" (line ");
+        this.push_usize(line);
+        this.push_str(", column ");
+        this.push_usize(position - line_start + 1);
+        this.push_str("):\n  ");
+
+        let mut marker_offset = position - excerpt_start;
+        if excerpt_start > line_start {
+            this.push_str("...");
+            marker_offset += 3;
+        }
+        let mut i = excerpt_start;
+        while i < excerpt_end {
+            // Replace non-printable chars so that the marker stays aligned.
+            this.push(match input[i] {
+                ch @ b' '..=b'~' => ch,
+                b'\t' => b' ',
+                _ => b'?',
+            });
+            i += 1;
+        }
+        if excerpt_end < line_end {
+            this.push_str("...");
+        }
+
+        this.push_str("\n  ");
+        while marker_offset > 0 {
+            this.push(b' ');
+            marker_offset -= 1;
+        }
+        this.push(b'^');
+        this
+    }
+}
+
+/// Partially decoded data left after processing the entire input.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Leftover {
+    /// Unpaired hex digit with the specified value.
+    HexDigit(u8),
+    /// Non-zero trailing bits that do not form a full byte.
+    Bits { value: u8, count: u8 },
+    /// Trailing bits with the value not revealed since it may be secret.
+    SecretBits { count: u8 },
+}
+
+impl Leftover {
+    const HEX_DIGITS: &'static [u8; 16] = b"0123456789abcdef";
+    /// Capacity sufficient for describing bits (there are at most 7 of them).
+    const BITS_CAPACITY: usize = 9;
+
+    /// Returns a hint explaining the likely cause of the leftover state.
+    const fn hint(self) -> &'static str {
+        match self {
+            Self::HexDigit(_) => {
+                "This usually means that the input is incorrect (e.g., an odd number of hex digits)."
+            }
+            Self::Bits { .. } => {
+                "This usually means that the input is truncated or not canonically encoded \
+                 (e.g., 'AR==' instead of 'AQ==' in Base64)."
+            }
+            Self::SecretBits { .. } => {
+                "This usually means that the input is incorrect (e.g., truncated)."
+            }
+        }
+    }
+
+    /// Returns binary representation of the leftover bits, e.g. `0b0110`.
+    const fn bits(value: u8, count: u8) -> TextBuffer<{ Self::BITS_CAPACITY }> {
+        let mut buffer = TextBuffer::new();
+        buffer.push_str("0b");
+        let mut i = count;
+        while i > 0 {
+            i -= 1;
+            buffer.push(if value & (1 << i) == 0 { b'0' } else { b'1' });
+        }
+        buffer
+    }
+}
+
+/// Capacity sufficient for error messages composed in compile time, such as
+/// [`NumberError::message()`]. Used both for `Display` and for panics, so that their wording
+/// cannot diverge.
+const MESSAGE_CAPACITY: usize = 128;
+
+type Message = TextBuffer<MESSAGE_CAPACITY>;
+
+/// Invalid number in a numeric byte list.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum NumberError {
+    /// ASCII char that is not a valid digit in the number base.
+    InvalidDigit { digit: u8, radix: u8 },
+    /// Radix prefix (e.g., `0x`) not followed by digits.
+    NoDigits,
+    /// Number exceeding the `u8` range.
+    Overflow,
+}
+
+impl NumberError {
+    const fn message(self, input_pos: usize) -> Message {
+        let mut message = Message::new();
+        match self {
+            Self::InvalidDigit { digit, radix } => {
+                message.push_str("Character '");
+                message.push(digit);
+                message.push_str("' at position ");
+                message.push_usize(input_pos);
+                message.push_str(" is not a valid digit in base ");
+                message.push_usize(radix as usize);
+            }
+            Self::NoDigits => {
+                message.push_str("Number at position ");
+                message.push_usize(input_pos);
+                message.push_str(" has a radix prefix, but no digits");
+            }
+            Self::Overflow => {
+                message.push_str("Number at position ");
+                message.push_usize(input_pos);
+                message.push_str(" does not fit into a byte");
+            }
+        }
+        message
+    }
+}
+
+/// Invalid escape sequence or unescaped char in an escaped byte string.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum EscapeError {
+    /// Char that must be escaped.
+    Unescaped(u8),
+    /// Escape sequence with an unknown char after the backslash.
+    Unknown(u8),
+    /// Backslash at the end of the input.
+    Unterminated,
+    /// `\x` escape not followed by 2 hex digits.
+    InvalidHex,
+    /// Octal escape exceeding the `u8` range.
+    OctalOverflow,
+}
+
+impl EscapeError {
+    const fn message(self, input_pos: usize) -> Message {
+        let mut message = Message::new();
+        match self {
+            Self::Unescaped(ch) => {
+                message.push_str("Character with decimal code ");
+                message.push_usize(ch as usize);
+                message.push_str(" at position ");
+                message.push_usize(input_pos);
+                message.push_str(" must be escaped");
+            }
+            Self::Unknown(ch) => {
+                message.push_str("Unknown escape sequence '\\");
+                message.push(ch);
+                message.push_str("' at position ");
+                message.push_usize(input_pos);
+            }
+            Self::Unterminated => {
+                message.push_str("Unterminated escape sequence at position ");
+                message.push_usize(input_pos);
+            }
+            Self::InvalidHex => {
+                message.push_str("Escape sequence at position ");
+                message.push_usize(input_pos);
+                message.push_str(" must have 2 hex digits");
+            }
+            Self::OctalOverflow => {
+                message.push_str("Octal escape sequence at position ");
+                message.push_usize(input_pos);
+                message.push_str(" does not fit into a byte");
+            }
+        }
+        message
+    }
+}
+
+/// Malformed UUID.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum UuidError {
+    /// Invalid number of chars (excluding the prefix and braces).
+    InvalidLength(usize),
+    /// Missing hyphen in the hyphenated form.
+    MissingHyphen,
+    /// Hyphen outside the group boundaries.
+    MisplacedHyphen,
+    /// Opening brace without the closing one.
+    UnclosedBrace,
+    /// Version not matching the expected one.
+    Version { actual: u8, expected: u8 },
+    /// Variant other than the one specified in RFC 9562.
+    Variant,
+}
+
+impl UuidError {
+    const fn message(self, input_pos: usize) -> Message {
+        let mut message = Message::new();
+        match self {
+            Self::InvalidLength(len) => {
+                message.push_str("Invalid UUID length ");
+                message.push_usize(len);
+                message.push_str("; expected 32 hex digits, or 36 chars in the hyphenated form");
+            }
+            Self::MissingHyphen => {
+                message.push_str("Expected '-' at position ");
+                message.push_usize(input_pos);
+                message.push_str(" of the UUID");
+            }
+            Self::MisplacedHyphen => {
+                message.push_str("Unexpected '-' at position ");
+                message.push_usize(input_pos);
+                message.push_str(" of the UUID; hyphens must separate 8-4-4-4-12 hex digit groups");
+            }
+            Self::UnclosedBrace => {
+                message.push_str("UUID starting with '{' at position ");
+                message.push_usize(input_pos);
+                message.push_str(" must end with '}'");
+            }
+            Self::Version { actual, expected } => {
+                message.push_str("UUID version ");
+                message.push_usize(actual as usize);
+                message.push_str(" at position ");
+                message.push_usize(input_pos);
+                message.push_str(" does not match the expected version ");
+                message.push_usize(expected as usize);
+            }
+            Self::Variant => {
+                message.push_str("UUID variant at position ");
+                message.push_usize(input_pos);
+                message.push_str(" is not the RFC 9562 variant");
+            }
+        }
+        message
+    }
+}
+
+/// Malformed hardware (MAC / EUI) address.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum AddressError {
+    /// Neither of the supported notations.
+    UnknownNotation,
+    /// Invalid number of chars for the notation with the specified separator.
+    InvalidLength { len: usize, separator: u8 },
+    /// Missing or inconsistent separator.
+    Separator(u8),
+}
+
+impl AddressError {
+    /// Returns the name of the notation with the specified separator, and the expected lengths
+    /// of 6-byte and 8-byte addresses in this notation.
+    const fn notation(separator: u8) -> (&'static str, usize, usize) {
+        match separator {
+            b':' => ("colon", 17, 23),
+            b'-' => ("hyphen", 17, 23),
+            _ => ("dot", 14, 19),
+        }
+    }
+
+    const fn message(self, input_pos: usize) -> Message {
+        let mut message = Message::new();
+        match self {
+            Self::UnknownNotation => {
+                message.push_str("Hardware address at position ");
+                message.push_usize(input_pos);
+                message.push_str(" is not in colon, hyphen or dot notation");
+            }
+            Self::InvalidLength { len, separator } => {
+                let (notation, short_len, long_len) = Self::notation(separator);
+                message.push_str("Invalid hardware address length: ");
+                message.push_usize(len);
+                message.push_str(" chars; expected ");
+                message.push_usize(short_len);
+                message.push_str(" or ");
+                message.push_usize(long_len);
+                message.push_str(" chars in the ");
+                message.push_str(notation);
+                message.push_str(" notation");
+            }
+            Self::Separator(separator) => {
+                message.push_str("Expected '");
+                message.push(separator);
+                message.push_str("' at position ");
+                message.push_usize(input_pos);
+                message.push_str(" of the hardware address");
+            }
+        }
+        message
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ErrorKind {
+    InvalidChar(CharError),
+    /// Same as `InvalidChar`, but the char is not revealed since the input may be secret.
+    InvalidSecretChar {
+        alphabet: Option<&'static str>,
+    },
+    InvalidNumber(NumberError),
+    InvalidEscape(EscapeError),
+    InvalidUuid(UuidError),
+    InvalidAddress(AddressError),
+    LeftoverState(Leftover),
+}
+
+/// Error that can occur during decoding, e.g. in [`StreamingDecoder`](crate::StreamingDecoder)
+/// or [`Decoder::try_decode_len()`](crate::Decoder::try_decode_len()).
+///
+/// In compile time, errors are reported as panics with the same message as
+/// the [`Display`](fmt::Display) implementation. If the input is available (as it is
+/// for the decoding methods and macros in this crate), panics for invalid chars additionally
+/// mention the line and column of the char, and show an excerpt of the line with
+/// the char marked. Columns are counted in bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct DecodeError {
+    kind: ErrorKind,
+    position: usize,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input_pos = self.position;
+        match self.kind {
+            ErrorKind::InvalidChar(CharError {
+                invalid_char,
+                alphabet,
+            }) => {
+                if !invalid_char.is_ascii() {
+                    write!(
+                        formatter,
+                        "Non-ASCII character with decimal code {invalid_char} \
+                         encountered at position {input_pos}"
+                    )
+                } else if let Some(alphabet) = alphabet {
+                    write!(
+                        formatter,
+                        "Character '{}' at position {input_pos} is not a part of \
+                         the decoder alphabet '{alphabet}'",
+                        invalid_char as char
+                    )
+                } else {
+                    write!(
+                        formatter,
+                        "Character '{}' at position {input_pos} is not a hex digit",
+                        invalid_char as char
+                    )
+                }
+            }
+            ErrorKind::InvalidSecretChar { alphabet } => {
+                if let Some(alphabet) = alphabet {
+                    write!(
+                        formatter,
+                        "Character at position {input_pos} is not a part of \
+                         the decoder alphabet '{alphabet}'"
+                    )
+                } else {
+                    write!(
+                        formatter,
+                        "Character at position {input_pos} is not a hex digit"
+                    )
+                }
+            }
+            ErrorKind::InvalidNumber(err) => formatter.write_str(err.message(input_pos).as_str()),
+            ErrorKind::InvalidEscape(err) => formatter.write_str(err.message(input_pos).as_str()),
+            ErrorKind::InvalidUuid(err) => formatter.write_str(err.message(input_pos).as_str()),
+            ErrorKind::InvalidAddress(err) => formatter.write_str(err.message(input_pos).as_str()),
+            ErrorKind::LeftoverState(leftover) => {
+                write!(
+                    formatter,
+                    "Left-over state after processing input of length {input_pos}: "
+                )?;
+                match leftover {
+                    Leftover::HexDigit(digit) => write!(
+                        formatter,
+                        "unpaired hex digit '{}'",
+                        Leftover::HEX_DIGITS[digit as usize] as char
+                    )?,
+                    Leftover::Bits { value, count } => write!(
+                        formatter,
+                        "non-zero trailing bits {} do not form a full byte",
+                        Leftover::bits(value, count).as_str()
+                    )?,
+                    Leftover::SecretBits { count } => {
+                        write!(formatter, "{count} trailing bit(s) do not form a full byte")?;
+                    }
+                }
+                write!(formatter, ". {}", leftover.hint())
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+impl DecodeError {
+    pub(crate) const fn invalid_char(err: CharError, position: usize) -> Self {
+        Self {
+            kind: ErrorKind::InvalidChar(err),
+            position,
+        }
+    }
+
+    /// Hides the offending char if this is an invalid char error. Other errors are returned as is.
+    pub(crate) const fn redact_char(self) -> Self {
+        match self.kind {
+            ErrorKind::InvalidChar(CharError { alphabet, .. }) => Self {
+                kind: ErrorKind::InvalidSecretChar { alphabet },
+                position: self.position,
+            },
+            _ => self,
+        }
+    }
+
+    pub(crate) const fn invalid_number(err: NumberError, position: usize) -> Self {
+        Self {
+            kind: ErrorKind::InvalidNumber(err),
+            position,
+        }
+    }
+
+    pub(crate) const fn invalid_escape(err: EscapeError, position: usize) -> Self {
+        Self {
+            kind: ErrorKind::InvalidEscape(err),
+            position,
+        }
+    }
+
+    pub(crate) const fn invalid_uuid(err: UuidError, position: usize) -> Self {
+        Self {
+            kind: ErrorKind::InvalidUuid(err),
+            position,
+        }
+    }
+
+    pub(crate) const fn invalid_address(err: AddressError, position: usize) -> Self {
+        Self {
+            kind: ErrorKind::InvalidAddress(err),
+            position,
+        }
+    }
+
+    pub(crate) const fn leftover_state(leftover: Leftover, position: usize) -> Self {
+        Self {
+            kind: ErrorKind::LeftoverState(leftover),
+            position,
+        }
+    }
+
+    /// Returns the position of the error in the input, i.e., the number of input bytes
+    /// preceding the offending char. For errors detected after processing the entire input
+    /// (e.g., an odd number of hex digits), this is the input length.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Panics with the message describing this error. This is the way to report errors
+    /// in compile time.
+    pub const fn panic(self) -> ! {
+        self.panic_with_context(("", "", ""), None)
+    }
+
+    /// Panics with the message describing this error, which includes the line, column
+    /// and an excerpt of the `input` around the offending char. `input` must be the input
+    /// that produced the error.
+    pub const fn panic_in(self, input: &[u8]) -> ! {
+        self.panic_with_context(("", "", ""), Some((input, 0, input.len())))
+    }
+
+    /// Panics with the error message prefixed by `context` (e.g., mentioning the JSON path
+    /// of the decoded value). `value_start..value_end` is the range of the decoded value
+    /// in `file`; the error position is relative to the value, but the line, column and excerpt
+    /// are relative to the file.
+    pub(crate) const fn panic_in_file(
+        self,
+        context: (&str, &str, &str),
+        file: &[u8],
+        (value_start, value_end): (usize, usize),
+    ) -> ! {
+        self.panic_with_context(context, Some((file, value_start, value_end)))
+    }
+
+    const fn panic_with_message(
+        (prefix, path, suffix): (&str, &str, &str),
+        message: &Message,
+        location: &str,
+    ) -> ! {
+        compile_panic!(
+            prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+            message.as_str() => clip(MESSAGE_CAPACITY, ""), location => clip(LOCATION_CAPACITY, "")
+        );
+    }
+
+    const fn panic_with_context(
+        self,
+        context: (&str, &str, &str),
+        input: Option<(&[u8], usize, usize)>,
+    ) -> ! {
+        let (prefix, path, suffix) = context;
+        let input_pos = self.position;
+        let location = match input {
+            Some((input, start, end)) if input_pos < end - start => {
+                TextBuffer::location(input, start + input_pos)
+            }
+            _ => TextBuffer::new(),
+        };
+        let location = location.as_str();
+
+        let err = match self.kind {
+            ErrorKind::InvalidChar(err) => err,
+            ErrorKind::InvalidSecretChar {
+                alphabet: Some(alphabet),
+            } => compile_panic!(
+                prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                "Character at position ", input_pos => fmt::<usize>(), " is not a part of \
+                the decoder alphabet '", Ascii::new(alphabet) => clip_ascii(64, ""), "'"
+            ),
+            ErrorKind::InvalidSecretChar { alphabet: None } => compile_panic!(
+                prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                "Character at position ", input_pos => fmt::<usize>(), " is not a hex digit"
+            ),
+            ErrorKind::InvalidNumber(err) => {
+                Self::panic_with_message(context, &err.message(input_pos), location)
+            }
+            ErrorKind::InvalidEscape(err) => {
+                Self::panic_with_message(context, &err.message(input_pos), location)
+            }
+            ErrorKind::InvalidUuid(err) => {
+                Self::panic_with_message(context, &err.message(input_pos), location)
+            }
+            ErrorKind::InvalidAddress(err) => {
+                Self::panic_with_message(context, &err.message(input_pos), location)
+            }
+            ErrorKind::LeftoverState(Leftover::HexDigit(digit)) => compile_panic!(
+                prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                "Left-over state after processing input of length ", input_pos => fmt::<usize>(),
+                ": unpaired hex digit '", Leftover::HEX_DIGITS[digit as usize] as char => fmt::<char>(),
+                "'. ", Leftover::HexDigit(digit).hint() => clip(128, "")
+            ),
+            ErrorKind::LeftoverState(Leftover::Bits { value, count }) => compile_panic!(
+                prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                "Left-over state after processing input of length ", input_pos => fmt::<usize>(),
+                ": non-zero trailing bits ",
+                Leftover::bits(value, count).as_str() => clip(Leftover::BITS_CAPACITY, ""),
+                " do not form a full byte. ", Leftover::Bits { value, count }.hint() => clip(128, "")
+            ),
+            ErrorKind::LeftoverState(Leftover::SecretBits { count }) => compile_panic!(
+                prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                "Left-over state after processing input of length ", input_pos => fmt::<usize>(),
+                ": ", count => fmt::<u8>(), " trailing bit(s) do not form a full byte. ",
+                Leftover::SecretBits { count }.hint() => clip(128, "")
+            ),
+        };
+
+        if err.invalid_char.is_ascii() {
+            if let Some(alphabet) = err.alphabet {
+                compile_panic!(
+                    prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                    "Character '", err.invalid_char as char => fmt::<char>(), "' at position ",
+                    input_pos => fmt::<usize>(), " is not a part of \
+                    the decoder alphabet '", Ascii::new(alphabet) => clip_ascii(64, ""), "'",
+                    location => clip(LOCATION_CAPACITY, "")
+                );
+            } else {
+                compile_panic!(
+                    prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                    "Character '", err.invalid_char as char => fmt::<char>(), "' at position ",
+                    input_pos => fmt::<usize>(), " is not a hex digit",
+                    location => clip(LOCATION_CAPACITY, "")
+                );
+            }
+        } else {
+            compile_panic!(
+                prefix => clip(32, ""), path => clip(64, "…"), suffix => clip(3, ""),
+                "Non-ASCII character with decimal code ", err.invalid_char => fmt::<u8>(),
+                " encountered at position ", input_pos => fmt::<usize>(),
+                location => clip(LOCATION_CAPACITY, "")
+            );
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Decoder for byte strings with escape sequences.
+
+use crate::{
+    decoder::HexDecoderState,
+    error::{DecodeError, EscapeError},
+    macros::impl_decoding_methods,
+};
+
+/// Decoder for byte strings with C / Rust / Python escape sequences, such as `\x12\x34\n\0ABC`.
+///
+/// Printable ASCII chars (including the space char) are passed through as is. All other bytes
+/// must be escaped; the following escape sequences are supported:
+///
+/// - `\xHH`, where `HH` are exactly 2 hex digits
+/// - `\N`, `\NN` or `\NNN`, where `N` are octal digits (e.g., `\0` or `\177`)
+/// - `\n`, `\r`, `\t`, `\a`, `\b`, `\f`, `\v` for the corresponding control chars
+/// - `\\`, `\'`, `\"`, `\?` for the chars after the backslash
+///
+/// Enclosing quotes (e.g., `b'...'` in Python `repr()` output) are not stripped and need to be
+/// removed from the input.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode, EscapedBytes};
+///
+/// const BYTES: [u8; 6] = EscapedBytes::decode(br"\x12\x34\n\0AB");
+/// assert_eq!(BYTES, [0x12, 0x34, b'\n', 0, b'A', b'B']);
+/// const HEADER: &[u8] = &decode!(EscapedBytes, br"\177ELF\2\1\1");
+/// assert_eq!(HEADER, b"\x7fELF\x02\x01\x01");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct EscapedBytes;
+
+impl EscapedBytes {
+    const fn is_printable(ch: u8) -> bool {
+        matches!(ch, b' '..=b'~')
+    }
+
+    /// Parses an escape sequence starting with a backslash at `start`. Returns the escaped byte
+    /// and the position after the sequence.
+    #[allow(clippy::cast_possible_truncation)] // checked
+    const fn parse_escape(input: &[u8], start: usize) -> Result<(u8, usize), DecodeError> {
+        if start + 1 == input.len() {
+            return Err(DecodeError::invalid_escape(
+                EscapeError::Unterminated,
+                start,
+            ));
+        }
+
+        Ok(match input[start + 1] {
+            b'x' => {
+                if start + 3 >= input.len() {
+                    return Err(DecodeError::invalid_escape(EscapeError::InvalidHex, start));
+                }
+                match (
+                    HexDecoderState::byte_value(input[start + 2]),
+                    HexDecoderState::byte_value(input[start + 3]),
+                ) {
+                    (Ok(hi), Ok(lo)) => ((hi << 4) + lo, start + 4),
+                    _ => return Err(DecodeError::invalid_escape(EscapeError::InvalidHex, start)),
+                }
+            }
+            b'0'..=b'7' => {
+                let mut pos = start + 1;
+                let mut value = 0_u16;
+                while pos < input.len() && pos < start + 4 && matches!(input[pos], b'0'..=b'7') {
+                    value = value * 8 + (input[pos] - b'0') as u16;
+                    pos += 1;
+                }
+                if value > u8::MAX as u16 {
+                    let err = EscapeError::OctalOverflow;
+                    return Err(DecodeError::invalid_escape(err, start));
+                }
+                (value as u8, pos)
+            }
+            b'n' => (b'\n', start + 2),
+            b'r' => (b'\r', start + 2),
+            b't' => (b'\t', start + 2),
+            b'a' => (0x07, start + 2),
+            b'b' => (0x08, start + 2),
+            b'f' => (0x0c, start + 2),
+            b'v' => (0x0b, start + 2),
+            ch @ (b'\\' | b'\'' | b'"' | b'?') => (ch, start + 2),
+            ch if Self::is_printable(ch) => {
+                return Err(DecodeError::invalid_escape(EscapeError::Unknown(ch), start));
+            }
+            ch => {
+                let err = EscapeError::Unescaped(ch);
+                return Err(DecodeError::invalid_escape(err, start + 1));
+            }
+        })
+    }
+
+    /// Decodes `input` into `output`.
+    const fn try_decode_into(input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError> {
+        let mut pos = 0;
+        let mut out_len = 0;
+        while pos < input.len() {
+            let (byte, next_pos) = match input[pos] {
+                b'\\' => match Self::parse_escape(input, pos) {
+                    Ok(parsed) => parsed,
+                    Err(err) => return Err(err),
+                },
+                ch if Self::is_printable(ch) => (ch, pos + 1),
+                ch => {
+                    let err = EscapeError::Unescaped(ch);
+                    return Err(DecodeError::invalid_escape(err, pos));
+                }
+            };
+            if out_len < output.len() {
+                output[out_len] = byte;
+            }
+            out_len += 1;
+            pos = next_pos;
+        }
+        Ok(out_len)
+    }
+}
+
+impl_decoding_methods!(
+    EscapedBytes,
+    invalid = "contains unescaped non-printable chars or invalid escape sequences"
+);
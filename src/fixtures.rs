@@ -0,0 +1,233 @@
+//! Parsing test vector files.
+
+use compile_fmt::{clip, compile_panic, fmt};
+
+use crate::error::DecodeError;
+
+/// Returns `input[start..end]`. Slice indexing is not available in const fns, so we emulate it.
+pub(crate) const fn subslice(input: &[u8], start: usize, end: usize) -> &[u8] {
+    input.split_at(end).0.split_at(start).1
+}
+
+pub(crate) const fn bytes_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the line starting at `pos` (without the terminating `\n`) and the start
+/// of the next line.
+const fn next_line(input: &[u8], pos: usize) -> (&[u8], usize) {
+    let mut end = pos;
+    while end < input.len() && input[end] != b'\n' {
+        end += 1;
+    }
+    let next_pos = if end < input.len() { end + 1 } else { end };
+    (subslice(input, pos, end), next_pos)
+}
+
+/// Splits a `key = value` line into a key and a value, both with whitespace trimmed.
+const fn split_key_value(line: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut i = 0;
+    while i < line.len() {
+        if line[i] == b'=' {
+            let (key, value) = line.split_at(i);
+            let value = value.split_at(1).1;
+            return Some((key.trim_ascii(), value.trim_ascii()));
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Key / value test vector file, such as NIST [CAVP] response (`.rsp`) files.
+///
+/// A file consists of lines, each of which is one of the following:
+///
+/// - `key = value` line
+/// - `[section]` header
+/// - `# comment`
+/// - an empty line.
+///
+/// Records are groups of consecutive `key = value` lines separated by empty lines or section headers.
+/// Comments are ignored.
+///
+/// [CAVP]: https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{Decoder, RspFile};
+///
+/// const VECTORS: &[u8] = b"
+/// # SHA-256 test vectors
+/// [L = 32]
+///
+/// Len = 0
+/// Msg = 00
+/// MD = e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+///
+/// Len = 8
+/// Msg = d3
+/// MD = 28969cdfa74a12c82f3bad960b0b000aca2ac329deea5c2328ebc6f2ba9802c1
+/// ";
+/// const MSG: [u8; 1] = Decoder::Hex.decode(RspFile::new(VECTORS).field(1, "Msg"));
+/// assert_eq!(MSG, [0xd3]);
+/// ```
+///
+/// See also the [`decode_field!`](crate::decode_field) macro.
+#[derive(Debug, Clone, Copy)]
+pub struct RspFile<'a> {
+    file: &'a [u8],
+    /// Range of the file considered by this instance (e.g., a section body).
+    start: usize,
+    end: usize,
+}
+
+impl<'a> RspFile<'a> {
+    /// Wraps the provided file contents.
+    pub const fn new(input: &'a [u8]) -> Self {
+        Self {
+            file: input,
+            start: 0,
+            end: input.len(),
+        }
+    }
+
+    const fn input(&self) -> &'a [u8] {
+        subslice(self.file, self.start, self.end)
+    }
+
+    /// Narrows this file to the section with the specified name, e.g. `"L = 32"`
+    /// for the `[L = 32]` header. The name is compared with the header contents
+    /// with surrounding whitespace trimmed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the section is not present in the file.
+    #[must_use]
+    pub const fn section(self, name: &str) -> Self {
+        let input = self.input();
+        let mut pos = 0;
+        while pos < input.len() {
+            let (line, next_pos) = next_line(input, pos);
+            pos = next_pos;
+            let line = line.trim_ascii();
+            if line.len() >= 2 && line[0] == b'[' && line[line.len() - 1] == b']' {
+                let header = subslice(line, 1, line.len() - 1).trim_ascii();
+                if bytes_eq(header, name.as_bytes()) {
+                    return Self {
+                        file: self.file,
+                        start: self.start + pos,
+                        end: self.start + Self::section_end(input, pos),
+                    };
+                }
+            }
+        }
+        compile_panic!("Section [", name => clip(64, "…"), "] is not present in the file");
+    }
+
+    /// Returns the end of the section body starting at `start`, i.e., the start of the next
+    /// section header or the end of `input`.
+    const fn section_end(input: &[u8], start: usize) -> usize {
+        let mut pos = start;
+        while pos < input.len() {
+            let (line, next_pos) = next_line(input, pos);
+            let line = line.trim_ascii();
+            if !line.is_empty() && line[0] == b'[' {
+                break;
+            }
+            pos = next_pos;
+        }
+        pos
+    }
+
+    /// Returns the value of the field with the specified `name` in the `record`-th record
+    /// of the file (0-based). The value has surrounding whitespace trimmed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the record is not present in the file, or if it does not contain the field.
+    pub const fn field(self, record: usize, name: &str) -> &'a [u8] {
+        let (start, end) = self.field_range(record, name);
+        subslice(self.file, start, end)
+    }
+
+    /// Returns the range of the field value in the file.
+    const fn field_range(self, record: usize, name: &str) -> (usize, usize) {
+        let input = self.input();
+        let mut pos = 0;
+        let mut record_idx = 0;
+        let mut in_record = false;
+        while pos < input.len() {
+            let (line, next_pos) = next_line(input, pos);
+            let line_end = self.start + pos + line.trim_ascii_end().len();
+            pos = next_pos;
+            let line = line.trim_ascii();
+            if line.is_empty() || line[0] == b'[' {
+                if in_record {
+                    if record_idx == record {
+                        Self::panic_on_missing_field(record, name);
+                    }
+                    record_idx += 1;
+                    in_record = false;
+                }
+                continue;
+            }
+            if line[0] == b'#' {
+                continue;
+            }
+
+            if let Some((key, value)) = split_key_value(line) {
+                in_record = true;
+                if record_idx == record && bytes_eq(key, name.as_bytes()) {
+                    // The value is trimmed, so it ends together with the trimmed line.
+                    return (line_end - value.len(), line_end);
+                }
+            }
+        }
+
+        if in_record && record_idx == record {
+            Self::panic_on_missing_field(record, name);
+        }
+        let record_count = if in_record {
+            record_idx + 1
+        } else {
+            record_idx
+        };
+        compile_panic!(
+            "Record #", record => fmt::<usize>(), " is not present in the file; the file contains ",
+            record_count => fmt::<usize>(), " record(s)"
+        );
+    }
+
+    #[doc(hidden)] // implementation detail of the `decode_field!` macro
+    pub const fn check_decoded_len(
+        self,
+        result: Result<usize, DecodeError>,
+        record: usize,
+        name: &str,
+    ) -> usize {
+        match result {
+            Ok(len) => len,
+            Err(err) => {
+                let context = ("Invalid value of field '", name, "': ");
+                err.panic_in_file(context, self.file, self.field_range(record, name))
+            }
+        }
+    }
+
+    const fn panic_on_missing_field(record: usize, name: &str) -> ! {
+        compile_panic!(
+            "Field '", name => clip(64, "…"), "' is not present in record #", record => fmt::<usize>()
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Extracting string values from JSON test vector files.
+
+use compile_fmt::{clip, compile_panic, fmt};
+
+use crate::{
+    error::DecodeError,
+    fixtures::{bytes_eq, subslice},
+};
+
+/// Segment of a JSON path.
+#[derive(Debug, Clone, Copy)]
+enum PathSegment<'a> {
+    Key(&'a [u8]),
+    Index(usize),
+}
+
+/// JSON file containing test vectors, e.g., from [Project Wycheproof].
+///
+/// This is a minimal JSON parser, which only allows extracting string values
+/// by their path in the JSON document. Paths consist of object keys separated by `.`
+/// and array indexes in square brackets, e.g. `testGroups[2].tests[5].msg`.
+/// Escape sequences in keys and extracted values are not processed.
+///
+/// [Project Wycheproof]: https://github.com/C2SP/wycheproof
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{Decoder, JsonFile};
+///
+/// const VECTORS: &[u8] = br#"{
+///   "algorithm": "EDDSA",
+///   "testGroups": [{
+///     "publicKey": { "pk": "7d4d0e7f6153a69b6242b522abbee685fda4420f8834b108c3bdae369ef549fa" },
+///     "tests": [{ "tcId": 1, "msg": "", "result": "valid" }]
+///   }]
+/// }"#;
+/// const PUBLIC_KEY: [u8; 32] =
+///     Decoder::Hex.decode(JsonFile::new(VECTORS).string("testGroups[0].publicKey.pk"));
+/// # assert_eq!(PUBLIC_KEY[0], 0x7d);
+/// ```
+///
+/// See also the [`decode_json!`](crate::decode_json) macro.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonFile<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> JsonFile<'a> {
+    /// Wraps the provided file contents.
+    pub const fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    /// Returns the contents of the string value at the specified `path`, not including
+    /// the enclosing quotes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path cannot be resolved, doesn't point to a string, or if the JSON
+    /// is malformed.
+    pub const fn string(self, path: &str) -> &'a [u8] {
+        let (start, end) = self.string_range(path);
+        subslice(self.input, start, end)
+    }
+
+    /// Returns the range of the string value at the specified `path` in the file.
+    const fn string_range(self, path: &str) -> (usize, usize) {
+        let mut pos = self.skip_whitespace(0);
+        let mut path_pos = 0;
+        while path_pos < path.len() {
+            let (segment, next_path_pos) = Self::next_segment(path, path_pos);
+            path_pos = next_path_pos;
+            pos = match segment {
+                PathSegment::Key(key) => self.object_member(pos, key, path),
+                PathSegment::Index(index) => self.array_element(pos, index, path),
+            };
+        }
+
+        if pos >= self.input.len() || self.input[pos] != b'"' {
+            Self::panic(path, pos, "expected a string");
+        }
+        let end = self.string_end(pos, path);
+        (pos + 1, end - 1)
+    }
+
+    #[doc(hidden)] // implementation detail of the `decode_json!` macro
+    pub const fn check_decoded_len(self, result: Result<usize, DecodeError>, path: &str) -> usize {
+        match result {
+            Ok(len) => len,
+            Err(err) => {
+                let context = ("Invalid string at JSON path '", path, "': ");
+                err.panic_in_file(context, self.input, self.string_range(path))
+            }
+        }
+    }
+
+    const fn panic(path: &str, pos: usize, reason: &str) -> ! {
+        compile_panic!(
+            "Cannot resolve JSON path '", path => clip(64, "…"), "': ", reason => clip(32, ""),
+            " at position ", pos => fmt::<usize>()
+        );
+    }
+
+    /// Parses the next segment of the path starting at `path_pos`.
+    const fn next_segment(path: &str, mut path_pos: usize) -> (PathSegment<'_>, usize) {
+        let path_bytes = path.as_bytes();
+        if path_bytes[path_pos] == b'.' {
+            path_pos += 1;
+        }
+
+        if path_pos < path_bytes.len() && path_bytes[path_pos] == b'[' {
+            let mut index = 0_usize;
+            let mut end = path_pos + 1;
+            while end < path_bytes.len() && path_bytes[end].is_ascii_digit() {
+                index = index * 10 + (path_bytes[end] - b'0') as usize;
+                end += 1;
+            }
+            if end == path_pos + 1 || end >= path_bytes.len() || path_bytes[end] != b']' {
+                compile_panic!(
+                    "Invalid JSON path '", path => clip(64, "…"), "': expected array index \
+                     at position ", path_pos => fmt::<usize>()
+                );
+            }
+            (PathSegment::Index(index), end + 1)
+        } else {
+            let mut end = path_pos;
+            while end < path_bytes.len() && path_bytes[end] != b'.' && path_bytes[end] != b'[' {
+                end += 1;
+            }
+            if end == path_pos {
+                compile_panic!(
+                    "Invalid JSON path '", path => clip(64, "…"), "': expected object key \
+                     at position ", path_pos => fmt::<usize>()
+                );
+            }
+            (PathSegment::Key(subslice(path_bytes, path_pos, end)), end)
+        }
+    }
+
+    const fn skip_whitespace(self, mut pos: usize) -> usize {
+        while pos < self.input.len() && self.input[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Skips whitespace and the expected `byte`.
+    const fn expect(self, pos: usize, byte: u8, path: &str, reason: &str) -> usize {
+        let pos = self.skip_whitespace(pos);
+        if pos >= self.input.len() || self.input[pos] != byte {
+            Self::panic(path, pos, reason);
+        }
+        pos + 1
+    }
+
+    /// Returns the position after the closing quote of the string starting at `pos`.
+    const fn string_end(self, mut pos: usize, path: &str) -> usize {
+        pos += 1; // skip the opening quote
+        while pos < self.input.len() {
+            match self.input[pos] {
+                b'"' => return pos + 1,
+                b'\\' => pos += 2,
+                _ => pos += 1,
+            }
+        }
+        Self::panic(path, pos, "unterminated string");
+    }
+
+    /// Returns the position after the value starting at `pos` (after whitespace).
+    const fn value_end(self, pos: usize, path: &str) -> usize {
+        let mut pos = self.skip_whitespace(pos);
+        if pos >= self.input.len() {
+            Self::panic(path, pos, "unexpected end of input");
+        }
+
+        match self.input[pos] {
+            b'"' => self.string_end(pos, path),
+            b'{' | b'[' => {
+                let mut depth = 0_usize;
+                while pos < self.input.len() {
+                    match self.input[pos] {
+                        b'"' => {
+                            pos = self.string_end(pos, path);
+                            continue;
+                        }
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return pos + 1;
+                            }
+                        }
+                        _ => { /* do nothing */ }
+                    }
+                    pos += 1;
+                }
+                Self::panic(path, pos, "unexpected end of input");
+            }
+            _ => {
+                // Number, `true`, `false` or `null`.
+                let start = pos;
+                while pos < self.input.len() {
+                    match self.input[pos] {
+                        b',' | b'}' | b']' => break,
+                        byte if byte.is_ascii_whitespace() => break,
+                        _ => pos += 1,
+                    }
+                }
+                if pos == start {
+                    Self::panic(path, pos, "unexpected char");
+                }
+                pos
+            }
+        }
+    }
+
+    /// Returns the position of the value of the member with the specified `key` in the object
+    /// starting at `pos`.
+    const fn object_member(self, pos: usize, key: &[u8], path: &str) -> usize {
+        let mut pos = self.expect(pos, b'{', path, "expected an object");
+        pos = self.skip_whitespace(pos);
+        if pos < self.input.len() && self.input[pos] == b'}' {
+            Self::panic(path, pos, "key is not present in the object");
+        }
+
+        loop {
+            pos = self.skip_whitespace(pos);
+            if pos >= self.input.len() || self.input[pos] != b'"' {
+                Self::panic(path, pos, "expected an object key");
+            }
+            let key_end = self.string_end(pos, path);
+            let member_key = subslice(self.input, pos + 1, key_end - 1);
+            pos = self.expect(key_end, b':', path, "expected ':'");
+            if bytes_eq(member_key, key) {
+                return self.skip_whitespace(pos);
+            }
+
+            pos = self.skip_whitespace(self.value_end(pos, path));
+            if pos < self.input.len() && self.input[pos] == b',' {
+                pos += 1;
+            } else if pos < self.input.len() && self.input[pos] == b'}' {
+                Self::panic(path, pos, "key is not present in the object");
+            } else {
+                Self::panic(path, pos, "expected ',' or '}'");
+            }
+        }
+    }
+
+    /// Returns the position of the `index`-th element in the array starting at `pos`.
+    const fn array_element(self, pos: usize, index: usize, path: &str) -> usize {
+        let mut pos = self.expect(pos, b'[', path, "expected an array");
+        pos = self.skip_whitespace(pos);
+        if pos < self.input.len() && self.input[pos] == b']' {
+            Self::panic(path, pos, "array index out of bounds");
+        }
+
+        let mut i = 0;
+        loop {
+            pos = self.skip_whitespace(pos);
+            if i == index {
+                return pos;
+            }
+
+            pos = self.skip_whitespace(self.value_end(pos, path));
+            if pos < self.input.len() && self.input[pos] == b',' {
+                pos += 1;
+            } else if pos < self.input.len() && self.input[pos] == b']' {
+                Self::panic(path, pos, "array index out of bounds");
+            } else {
+                Self::panic(path, pos, "expected ',' or ']'");
+            }
+            i += 1;
+        }
+    }
+}
@@ -2,9 +2,11 @@
 //! Works on stable Rust and in no-std environments. Base-(2,4,8,16,32,64) encodings with
 //! custom alphabets are supported as well via [`Encoding`].
 //!
-//! [`Decoder`] is the base type encapsulating decoding logic, with [`SkipWhitespace`]
-//! and [`Pem`] types providing its variations with slightly different properties.
-//! (For example, `Pem` allows to parse PEM files.)
+//! [`Decoder`] is the base type encapsulating decoding logic, with [`SkipWhitespace`],
+//! [`ConstantTime`] and [`Pem`] types providing its variations with slightly different properties.
+//! (For example, `Pem` allows to parse PEM files.) [`ByteList`] decodes delimited lists of numbers,
+//! such as C array literals, [`EscapedBytes`] decodes byte strings with escape sequences,
+//! and [`Uuid`] and [`MacAddress`] decode UUIDs and hardware addresses respectively.
 //!
 //! Methods in base types require specifying the length of the output byte array, either in its type,
 //! or using the turbofish syntax (see the examples below). To avoid this, you can instead use
@@ -12,6 +14,31 @@
 //!
 //! Conversions are primarily useful for testing, but can be used in other contexts as well.
 //!
+//! # Crate features
+//!
+//! ## `std`
+//!
+//! *(Off by default)*
+//!
+//! Enables `DecodingReader` and `EncodingWriter`, adapters for the `std::io::{Read, Write}` traits
+//! decoding text supplied by the wrapped reader / encoding data for the wrapped writer.
+//!
+//! ## `zeroize`
+//!
+//! *(Off by default)*
+//!
+//! Enables the `Secret` wrapper and `decode_secret()` methods for decoding secrets in runtime.
+//! Decoded secrets are wiped on drop using the [`zeroize`] crate.
+//!
+//! [`zeroize`]: https://docs.rs/zeroize/
+//!
+//! ## `serde`
+//!
+//! *(Off by default)*
+//!
+//! Exposes the `serde` module with helpers for (de)serializing byte arrays as hex / base64 strings
+//! via `#[serde(with = ...)]` attributes.
+//!
 //! # Alternatives
 //!
 //! [`hex-literal`] and [`binary_macros`] crates expose similar functionality
@@ -98,22 +125,68 @@
 
 #![no_std]
 // Documentation settings.
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(html_root_url = "https://docs.rs/const-decoder/0.4.0")]
 // Linter settings.
 #![warn(missing_debug_implementations, missing_docs, bare_trait_objects)]
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::must_use_candidate, clippy::shadow_unrelated)]
 
+#[cfg(feature = "std")]
+pub use crate::io::{DecodingReader, EncodingWriter};
+#[cfg(feature = "zeroize")]
+pub use crate::secret::Secret;
+
+#[cfg(feature = "std")]
+extern crate std;
 pub use crate::{
+    byte_list::ByteList,
     decoder::{Decoder, Encoding},
-    macros::DecoderWrapper,
-    wrappers::{Pem, SkipWhitespace},
+    digest::{sha1, sha256, sha512},
+    error::DecodeError,
+    escaped::EscapedBytes,
+    fixtures::RspFile,
+    json::JsonFile,
+    mac_address::MacAddress,
+    streaming::StreamingDecoder,
+    uuid::Uuid,
+    words::{BigEndian, ByteOrder, LittleEndian, Word},
+    wrappers::{ConstantTime, Pem, SkipWhitespace},
 };
 
+/// Implementation details of the macros in this crate. Not a part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::{
+        digest::assert_digest,
+        macros::{assert_item_len, assert_split_len, padding_len, split_off, validate_utf8},
+        words::{convert_byte_order, word_count, words_as_bytes_mut},
+    };
+}
+
+mod byte_list;
 mod decoder;
+mod digest;
+#[cfg(any(feature = "serde", feature = "std"))]
+mod encoder;
+mod error;
+mod escaped;
+mod fixtures;
+#[cfg(feature = "std")]
+mod io;
+mod json;
+mod mac_address;
 mod macros;
+#[cfg(feature = "zeroize")]
+mod secret;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+mod streaming;
 #[cfg(test)]
 mod tests;
+mod uuid;
+mod words;
 mod wrappers;
 
 #[cfg(doctest)]
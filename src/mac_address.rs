@@ -0,0 +1,109 @@
+//! Decoder for hardware (MAC / EUI) addresses.
+
+use crate::{
+    decoder::HexDecoderState,
+    error::{AddressError, DecodeError},
+    macros::impl_decoding_methods,
+};
+
+/// Decoder for hardware addresses: MAC / EUI-48 addresses decoding to 6 bytes and EUI-64 addresses
+/// decoding to 8 bytes.
+///
+/// The following notations are supported:
+///
+/// - Colon notation: `00:1a:2b:3c:4d:5e`
+/// - Hyphen notation: `00-1A-2B-3C-4D-5E`
+/// - Cisco dot notation: `001a.2b3c.4d5e`
+///
+/// Hex digits are case-insensitive. The group structure is validated: groups must consist
+/// of 2 hex digits (4 digits in the dot notation), and all separators must be the same.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode, MacAddress};
+///
+/// const MAC: [u8; 6] = MacAddress::decode(b"00:1a:2b:3c:4d:5e");
+/// assert_eq!(MAC, [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+/// assert_eq!(MacAddress::decode::<6>(b"00-1A-2B-3C-4D-5E"), MAC);
+/// assert_eq!(MacAddress::decode::<6>(b"001a.2b3c.4d5e"), MAC);
+///
+/// const EUI64: &[u8] = &decode!(MacAddress, b"02:00:5e:10:00:00:00:01");
+/// assert_eq!(EUI64, [2, 0, 0x5e, 0x10, 0, 0, 0, 1]);
+/// ```
+///
+/// Inconsistent separators are rejected:
+///
+/// ```compile_fail
+/// # use const_decoder::MacAddress;
+/// const MAC: [u8; 6] = MacAddress::decode(b"00:1a-2b:3c:4d:5e");
+/// // panics: Expected ':' at position 5 of the hardware address
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MacAddress;
+
+impl MacAddress {
+    /// Detects the separator and the number of hex digits in a group.
+    const fn notation(input: &[u8]) -> Result<(u8, usize), DecodeError> {
+        if input.len() > 2 && matches!(input[2], b':' | b'-') {
+            Ok((input[2], 2))
+        } else if input.len() > 4 && input[4] == b'.' {
+            Ok((b'.', 4))
+        } else {
+            Err(DecodeError::invalid_address(
+                AddressError::UnknownNotation,
+                0,
+            ))
+        }
+    }
+
+    /// Decodes `input` into `output`.
+    const fn try_decode_into(input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError> {
+        let (separator, group_len) = match Self::notation(input) {
+            Ok(notation) => notation,
+            Err(err) => return Err(err),
+        };
+        // Each group is followed by a separator, except for the last one.
+        let byte_count = if (input.len() + 1) % (group_len + 1) == 0 {
+            (input.len() + 1) / (group_len + 1) * group_len / 2
+        } else {
+            0
+        };
+        if byte_count != 6 && byte_count != 8 {
+            let err = AddressError::InvalidLength {
+                len: input.len(),
+                separator,
+            };
+            return Err(DecodeError::invalid_address(err, 0));
+        }
+
+        let mut state = HexDecoderState::new(false);
+        let mut out_len = 0;
+        let mut pos = 0;
+        while pos < input.len() {
+            let ch = input[pos];
+            if pos % (group_len + 1) == group_len {
+                if ch != separator {
+                    let err = AddressError::Separator(separator);
+                    return Err(DecodeError::invalid_address(err, pos));
+                }
+            } else {
+                let (new_state, byte) = match state.update(ch) {
+                    Ok(updated) => updated,
+                    Err(err) => return Err(DecodeError::invalid_char(err, pos)),
+                };
+                state = new_state;
+                if let Some(byte) = byte {
+                    if out_len < output.len() {
+                        output[out_len] = byte;
+                    }
+                    out_len += 1;
+                }
+            }
+            pos += 1;
+        }
+        Ok(out_len)
+    }
+}
+
+impl_decoding_methods!(MacAddress, invalid = "is not a valid hardware address");
@@ -1,16 +1,18 @@
 //! `decode!` macro and the associated helper types.
 
-use crate::{
-    decoder::Decoder,
-    wrappers::{Pem, SkipWhitespace, Skipper},
-};
+use compile_fmt::{compile_assert, compile_panic, fmt};
 
 /// Computes the output length in compile time and decodes the input. This allows to skip specifying
 /// output length manually.
 ///
 /// The macro accepts two comma-separate expressions. The first arg must evaluate to [`Decoder`],
-/// [`SkipWhitespace`], or [`Pem`]. The second argument must evaluate to `&[u8]`. Both expressions
-/// must be assignable to constants. The output of a macro is an array `[u8; N]` with the decoded bytes.
+/// [`SkipWhitespace`], [`ConstantTime`], [`Pem`], or a [custom decoder](#custom-decoders).
+/// The second argument must evaluate to `&[u8]`. Both expressions must be assignable to constants.
+/// The output of a macro is an array `[u8; N]` with the decoded bytes.
+///
+/// Several inputs can be decoded and concatenated into a single array as well. If all inputs
+/// use the same decoder, they can be specified after it: `decode!(decoder, input1, input2, ..)`.
+/// Otherwise, each input needs to be preceded by its decoder: `decode!(decoder1 => input1, decoder2 => input2, ..)`.
 ///
 /// # Examples
 ///
@@ -49,45 +51,625 @@ use crate::{
 ///       -----END PRIVATE KEY-----",
 /// );
 /// ```
+///
+/// ## Concatenating inputs
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, Pem};
+/// const KEY: &[u8] = &decode!(
+///     Decoder::Hex,
+///     b"9e55d1e1aa1f455b8baad9fdf975503655f8b359d542fa7e4ce84106d625b352",
+///     b"06fac1f22240cffd637ead6647188429fafda9c9cb7eae43386ac17f61115075",
+/// );
+/// assert_eq!(KEY.len(), 64);
+///
+/// const MIXED: &[u8] = &decode!(
+///     Decoder::Hex => b"0001",
+///     Decoder::Base64 => b"dGVzdA==",
+///     Decoder::Hex.skip_whitespace() => b"ff ff",
+/// );
+/// assert_eq!(MIXED, b"\x00\x01test\xff\xff");
+/// ```
+///
+/// ## Padding
+///
+/// The decoded data can be right-aligned in a larger array, with the leading bytes filled
+/// with zeros, by specifying `pad_to = N` after the input. This is useful for big-endian integers
+/// such as RSA exponents. Compilation fails if the input decodes to more than `N` bytes.
+/// Padding is only supported for a single input.
+///
+/// ```
+/// # use const_decoder::{decode, Decoder};
+/// const EXPONENT: [u8; 8] = decode!(Decoder::Hex, b"010001", pad_to = 8);
+/// assert_eq!(EXPONENT, [0, 0, 0, 0, 0, 1, 0, 1]);
+/// ```
+///
+/// ## Pinning digests
+///
+/// The decoded data can be pinned to a certain SHA-1, SHA-256 or SHA-512 digest, specified
+/// as a hex string after the input. If the digest of the decoded data does not match,
+/// compilation will fail. For multiple inputs, the digest is computed over the concatenated data.
+///
+/// ```
+/// # use const_decoder::{decode, Decoder};
+/// const TEST: &[u8] = &decode!(
+///     Decoder::Base64,
+///     b"VGVzdCBzdHJpbmc=",
+///     sha256 = "a3e49d843df13c2e2a7786f6ecd7e0d184f45d718d1ac1a8a63e570466e489dd",
+/// );
+/// const CONCAT: &[u8] = &decode!(
+///     Decoder::Hex => b"5465737420",
+///     Decoder::Base64 => b"c3RyaW5n",
+///     sha256 = "a3e49d843df13c2e2a7786f6ecd7e0d184f45d718d1ac1a8a63e570466e489dd",
+/// );
+/// assert_eq!(CONCAT, TEST);
+/// ```
+///
+/// ```compile_fail
+/// # use const_decoder::{decode, Decoder};
+/// const TEST: &[u8] = &decode!(
+///     Decoder::Base64,
+///     b"VGVzdCBzdHJpbmc=",
+///     sha1 = "0000000000000000000000000000000000000000",
+/// );
+/// ```
+///
+/// ## Custom decoders
+///
+/// The decoder arg of this and other decoding macros is not restricted to the types
+/// from this crate. Since trait methods cannot be called in compile time, the macros
+/// rely on a method naming convention instead: a decoder must have the following inherent
+/// `const` methods.
+///
+/// - `const fn decode_len(&self, input: &[u8]) -> usize` computes the length of the decoded
+///   `input` in bytes, panicking if the input is invalid.
+/// - `const fn decode_into(&self, input: &[u8], output: &mut [u8])` decodes `input` into `output`;
+///   the length of `output` is equal to the value returned by `decode_len`.
+/// - `const fn try_decode_len(&self, input: &[u8]) -> Result<usize, DecodeError>` is only
+///   required by [`decode_json!`](crate::decode_json) and [`decode_field!`](crate::decode_field).
+///   It is similar to `decode_len`, but returns a [`DecodeError`] on invalid input instead
+///   of panicking.
+///
+/// All decoders in this crate follow this convention, so any of them (e.g., [`Pem`]
+/// or [`ByteList`]) can be passed to the decoding macros, and custom decoders can be implemented
+/// on top of them.
+///
+/// ```
+/// use const_decoder::{decode, Decoder};
+///
+/// /// Hex decoder accepting an optional `0x` prefix.
+/// struct PrefixedHex;
+///
+/// impl PrefixedHex {
+///     const fn strip_prefix(input: &[u8]) -> &[u8] {
+///         match input {
+///             [b'0', b'x', rest @ ..] => rest,
+///             _ => input,
+///         }
+///     }
+///
+///     const fn decode_len(&self, input: &[u8]) -> usize {
+///         Decoder::Hex.decode_len(Self::strip_prefix(input))
+///     }
+///
+///     const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+///         Decoder::Hex.decode_into(Self::strip_prefix(input), output);
+///     }
+/// }
+///
+/// const ADDRESS: &[u8] = &decode!(PrefixedHex, b"0xc0ffee");
+/// assert_eq!(ADDRESS, [0xc0, 0xff, 0xee]);
+/// ```
+///
+/// [`Decoder`]: crate::Decoder
+/// [`SkipWhitespace`]: crate::SkipWhitespace
+/// [`ConstantTime`]: crate::ConstantTime
+/// [`Pem`]: crate::Pem
+/// [`DecodeError`]: crate::DecodeError
+/// [`ByteList`]: crate::ByteList
 #[macro_export]
 macro_rules! decode {
+    (@pinned $hash:path, [$($decoder:expr => $bytes:expr,)+], $digest:expr) => {{
+        const __OUTPUT_LEN: usize = 0 $(+ $decoder.decode_len($bytes))+;
+        const __OUTPUT: [u8; __OUTPUT_LEN] = $crate::decode!(@concat $($decoder => $bytes,)+);
+        const _: () = $crate::__private::assert_digest(
+            &$hash(&__OUTPUT),
+            &$crate::decode!($crate::Decoder::Hex.skip_whitespace(), $digest.as_bytes()),
+        );
+        __OUTPUT
+    }};
+    (@concat $($decoder:expr => $bytes:expr,)+) => {{
+        const __OUTPUT_LEN: usize = 0 $(+ $decoder.decode_len($bytes))+;
+        let mut __output = [0_u8; __OUTPUT_LEN];
+        let mut __rest: &mut [u8] = &mut __output;
+        $(
+        let (__part, __tail) = __rest.split_at_mut($decoder.decode_len($bytes));
+        $decoder.decode_into($bytes, __part);
+        __rest = __tail;
+        )+
+        let _ = __rest;
+        __output
+    }};
+    // Options after multiple inputs. `$inputs` are accumulated `decoder => input,` pairs.
+    (@options [$($inputs:tt)+] $(,)?) => {
+        $crate::decode!(@concat $($inputs)+)
+    };
+    (@options [$($inputs:tt)+], sha1 = $digest:expr $(,)?) => {
+        $crate::decode!(@pinned $crate::sha1, [$($inputs)+], $digest)
+    };
+    (@options [$($inputs:tt)+], sha256 = $digest:expr $(,)?) => {
+        $crate::decode!(@pinned $crate::sha256, [$($inputs)+], $digest)
+    };
+    (@options [$($inputs:tt)+], sha512 = $digest:expr $(,)?) => {
+        $crate::decode!(@pinned $crate::sha512, [$($inputs)+], $digest)
+    };
+    (@options [$($inputs:tt)+], pad_to = $len:expr $(,)?) => {
+        compile_error!("`pad_to` is not supported for multiple inputs")
+    };
+    // Inputs sharing a single decoder.
+    (@shared $decoder:expr, [$($inputs:tt)*], sha1 $($rest:tt)*) => {
+        $crate::decode!(@options [$($inputs)*], sha1 $($rest)*)
+    };
+    (@shared $decoder:expr, [$($inputs:tt)*], sha256 $($rest:tt)*) => {
+        $crate::decode!(@options [$($inputs)*], sha256 $($rest)*)
+    };
+    (@shared $decoder:expr, [$($inputs:tt)*], sha512 $($rest:tt)*) => {
+        $crate::decode!(@options [$($inputs)*], sha512 $($rest)*)
+    };
+    (@shared $decoder:expr, [$($inputs:tt)*], pad_to $($rest:tt)*) => {
+        $crate::decode!(@options [$($inputs)*], pad_to $($rest)*)
+    };
+    (@shared $decoder:expr, [$($inputs:tt)*], $bytes:expr $(, $($rest:tt)*)?) => {
+        $crate::decode!(@shared $decoder, [$($inputs)* $decoder => $bytes,] $(, $($rest)*)?)
+    };
+    (@shared $decoder:expr, [$($inputs:tt)*] $(,)?) => {
+        $crate::decode!(@options [$($inputs)*])
+    };
+    // Inputs with individual decoders.
+    (@pairs [$($inputs:tt)*], $decoder:expr => $bytes:expr $(, $($rest:tt)*)?) => {
+        $crate::decode!(@pairs [$($inputs)* $decoder => $bytes,] $(, $($rest)*)?)
+    };
+    (@pairs [$($inputs:tt)*] $($rest:tt)*) => {
+        $crate::decode!(@options [$($inputs)*] $($rest)*)
+    };
+
+    ($decoder:expr, $bytes:expr, sha1 = $digest:expr $(,)?) => {
+        $crate::decode!(@pinned $crate::sha1, [$decoder => $bytes,], $digest)
+    };
+    ($decoder:expr, $bytes:expr, sha256 = $digest:expr $(,)?) => {
+        $crate::decode!(@pinned $crate::sha256, [$decoder => $bytes,], $digest)
+    };
+    ($decoder:expr, $bytes:expr, sha512 = $digest:expr $(,)?) => {
+        $crate::decode!(@pinned $crate::sha512, [$decoder => $bytes,], $digest)
+    };
+    ($decoder:expr, $bytes:expr, pad_to = $len:expr $(,)?) => {{
+        const __PADDING_LEN: usize = $crate::__private::padding_len($decoder.decode_len($bytes), $len);
+        let mut __output = [0_u8; $len];
+        let (_, __value) = __output.split_at_mut(__PADDING_LEN);
+        $decoder.decode_into($bytes, __value);
+        __output
+    }};
     ($decoder:expr, $bytes:expr $(,)?) => {{
-        const __OUTPUT_LEN: usize = $crate::DecoderWrapper($decoder).decode_len($bytes);
-        $crate::DecoderWrapper($decoder).decode::<__OUTPUT_LEN>($bytes) as [u8; __OUTPUT_LEN]
+        const __OUTPUT_LEN: usize = $decoder.decode_len($bytes);
+        let mut __output = [0_u8; __OUTPUT_LEN];
+        $decoder.decode_into($bytes, &mut __output);
+        __output
     }};
+    ($decoder:expr => $($rest:tt)+) => {
+        $crate::decode!(@pairs [], $decoder => $($rest)+)
+    };
+    ($decoder:expr, $($rest:tt)+) => {
+        $crate::decode!(@shared $decoder, [], $($rest)+)
+    };
 }
 
-#[derive(Debug)]
-#[doc(hidden)] // implementation detail of the `decode!` macro
-pub struct DecoderWrapper<T>(pub T);
+/// Decodes the input into an array of words, computing the array length in compile time.
+/// This is a [`decode!`] analogue for [`Decoder::decode_words()`](crate::Decoder::decode_words()).
+///
+/// The macro accepts four comma-separated args. The first two are the same as for [`decode!`];
+/// the third one is the word type (one of `u16`, `u32`, `u64` or `u128`), and the fourth one
+/// is the byte order ([`BigEndian`](crate::BigEndian) or [`LittleEndian`](crate::LittleEndian)).
+/// The output of a macro is an array `[W; N]`, where `W` is the word type.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode_words, BigEndian, Decoder};
+///
+/// // Limbs of the Curve25519 base field modulus.
+/// const MODULUS: &[u64] = &decode_words!(
+///     Decoder::Hex.skip_whitespace(),
+///     b"7fffffffffffffff ffffffffffffffff ffffffffffffffff ffffffffffffffed",
+///     u64,
+///     BigEndian,
+/// );
+/// # assert_eq!(MODULUS.len(), 4);
+/// # assert_eq!(MODULUS[3], 0xffff_ffff_ffff_ffed);
+/// ```
+///
+/// Inputs not divisible into words lead to a compilation error:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode_words, Decoder, LittleEndian};
+/// const WORDS: &[u32] = &decode_words!(Decoder::Hex, b"c0ffee", u32, LittleEndian);
+/// ```
+#[macro_export]
+macro_rules! decode_words {
+    ($decoder:expr, $bytes:expr, $word:ty, $order:ty $(,)?) => {{
+        const __OUTPUT_LEN: usize =
+            $crate::__private::word_count::<$word>($decoder.decode_len($bytes));
+        let mut __output = [<$word as $crate::Word>::ZERO; __OUTPUT_LEN];
+        let __bytes = $crate::__private::words_as_bytes_mut(&mut __output);
+        $decoder.decode_into($bytes, __bytes);
+        $crate::__private::convert_byte_order::<$word, $order>(__bytes);
+        __output
+    }};
+}
 
-impl DecoderWrapper<Decoder> {
-    pub const fn decode_len(&self, input: &[u8]) -> usize {
-        self.0.do_decode_len(input, None)
-    }
+/// Decodes the input into a UTF-8 string. This is a [`decode!`] analogue for textual data.
+///
+/// The macro accepts the same args as [`decode!`]. The output of the macro is a `&'static str`.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode_str, Decoder};
+///
+/// const TEXT: &str = decode_str!(Decoder::Base64, b"VGVzdCBzdHJpbmc=");
+/// assert_eq!(TEXT, "Test string");
+/// ```
+///
+/// Decoded data that is not valid UTF-8 leads to a compilation error:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode_str, Decoder};
+/// const TEXT: &str = decode_str!(Decoder::Hex, b"c0ffee");
+/// ```
+#[macro_export]
+macro_rules! decode_str {
+    ($decoder:expr, $bytes:expr $(,)?) => {{
+        const __BYTES: &[u8] = &$crate::decode!($decoder, $bytes);
+        const __STR: &str = $crate::__private::validate_utf8(__BYTES);
+        __STR
+    }};
+}
 
-    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
-        self.0.decode(input)
+#[doc(hidden)] // implementation detail of the `decode_str!` macro
+pub const fn validate_utf8(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(err) => {
+            let pos = err.valid_up_to();
+            compile_panic!(
+                "Decoded bytes are not valid UTF-8: invalid sequence at position ",
+                pos => fmt::<usize>(), " of the decoded output"
+            );
+        }
     }
 }
 
-impl DecoderWrapper<SkipWhitespace> {
-    pub const fn decode_len(&self, input: &[u8]) -> usize {
-        let Self(SkipWhitespace(decoder)) = self;
-        decoder.do_decode_len(input, Some(Skipper::Whitespace))
-    }
+/// Decodes a list of inputs into an array of equally sized byte arrays, computing the array
+/// dimensions in compile time. This is useful for tables of constants, such as test keys.
+///
+/// The first arg of the macro is the same as for [`decode!`]. The second arg is a bracketed list
+/// of inputs, each of which must evaluate to `&[u8]`. The output of the macro is an array
+/// `[[u8; N]; K]`, where `N` is the length of the first decoded input and `K` is the number of inputs.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode_array, Decoder};
+///
+/// const KEYS: &[[u8; 4]] = &decode_array!(
+///     Decoder::Hex,
+///     [b"c0ffee00", b"deadbeef", b"01234567"],
+/// );
+/// assert_eq!(KEYS[1], [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+///
+/// Inputs with differing decoded lengths lead to a compilation error:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode_array, Decoder};
+/// const KEYS: &[[u8; 4]] = &decode_array!(Decoder::Hex, [b"c0ffee00", b"c0ffee"]);
+/// ```
+#[macro_export]
+macro_rules! decode_array {
+    ($decoder:expr, [$($bytes:expr),+ $(,)?] $(,)?) => {{
+        const __INPUTS: &[&[u8]] = &[$($bytes),+];
+        const __ITEM_LEN: usize = {
+            let item_len = $decoder.decode_len(__INPUTS[0]);
+            let mut i = 1;
+            while i < __INPUTS.len() {
+                let len = $decoder.decode_len(__INPUTS[i]);
+                $crate::__private::assert_item_len(i, len, item_len);
+                i += 1;
+            }
+            item_len
+        };
+        let mut __output = [[0_u8; __ITEM_LEN]; __INPUTS.len()];
+        let mut __i = 0;
+        while __i < __INPUTS.len() {
+            $decoder.decode_into(__INPUTS[__i], &mut __output[__i]);
+            __i += 1;
+        }
+        __output
+    }};
+}
 
-    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
-        self.0.decode(input)
-    }
+/// Decodes the input and splits the decoded bytes into several fixed-size arrays. This is useful
+/// for test fixtures concatenating several values, such as a public key and a signature.
+///
+/// The first two args of the macro are the same as for [`decode!`]. The input must be followed
+/// by `=>` and either a comma-separated list of field lengths, or a struct literal with field lengths
+/// in place of field values. In the first case, the output of the macro is a tuple of arrays
+/// `([u8; N0], [u8; N1], ..)`; in the second case, it is an instance of the specified struct.
+/// The sum of the field lengths must be equal to the decoded length of the input; this is checked
+/// in compile time.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode_split, Decoder};
+///
+/// const INPUT: &[u8] = b"c0ffee0123456789";
+/// const FIELDS: ([u8; 3], [u8; 5]) = decode_split!(Decoder::Hex, INPUT => 3, 5);
+/// assert_eq!(FIELDS.0, [0xc0, 0xff, 0xee]);
+/// assert_eq!(FIELDS.1, [0x01, 0x23, 0x45, 0x67, 0x89]);
+///
+/// struct Signed {
+///     message: [u8; 4],
+///     tag: [u8; 4],
+/// }
+///
+/// const SIGNED: Signed =
+///     decode_split!(Decoder::Base64, b"VGVzdMD/7gA=" => Signed { message: 4, tag: 4 });
+/// assert_eq!(SIGNED.message, *b"Test");
+/// assert_eq!(SIGNED.tag, [0xc0, 0xff, 0xee, 0]);
+/// ```
+///
+/// Lengths not matching the decoded length lead to a compilation error:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode_split, Decoder};
+/// const FIELDS: ([u8; 3], [u8; 4]) = decode_split!(Decoder::Hex, b"c0ffee0123456789" => 3, 4);
+/// ```
+#[macro_export]
+macro_rules! decode_split {
+    (@decode $decoder:expr, $bytes:expr, $($len:expr),+) => {{
+        const __OUTPUT_LEN: usize = 0 $(+ $len)+;
+        const _: () = $crate::__private::assert_split_len($decoder.decode_len($bytes), __OUTPUT_LEN);
+        let mut __output = [0_u8; __OUTPUT_LEN];
+        $decoder.decode_into($bytes, &mut __output);
+        __output
+    }};
+    ($decoder:expr, $bytes:expr => $name:path { $($field:ident: $len:expr),+ $(,)? } $(,)?) => {{
+        let __output = $crate::decode_split!(@decode $decoder, $bytes, $($len),+);
+        let mut __offset = 0;
+        $name {
+            $($field: $crate::__private::split_off::<{ $len }>(&__output, &mut __offset),)+
+        }
+    }};
+    ($decoder:expr, $bytes:expr => $($len:expr),+ $(,)?) => {{
+        let __output = $crate::decode_split!(@decode $decoder, $bytes, $($len),+);
+        let mut __offset = 0;
+        ($($crate::__private::split_off::<{ $len }>(&__output, &mut __offset),)+)
+    }};
 }
 
-impl DecoderWrapper<Pem> {
-    pub const fn decode_len(&self, input: &[u8]) -> usize {
-        Decoder::Base64.do_decode_len(input, Some(Skipper::Pem))
-    }
+#[doc(hidden)] // implementation detail of the `decode_split!` macro
+pub const fn assert_split_len(len: usize, expected_len: usize) {
+    compile_assert!(
+        len == expected_len,
+        "Input decodes to ", len => fmt::<usize>(), " bytes, while the split field lengths sum to ",
+        expected_len => fmt::<usize>(), " bytes"
+    );
+}
 
-    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
-        Pem::decode(input)
+#[doc(hidden)] // implementation detail of the `decode_split!` macro
+pub const fn split_off<const N: usize>(bytes: &[u8], offset: &mut usize) -> [u8; N] {
+    let mut output = [0_u8; N];
+    let mut i = 0;
+    while i < N {
+        output[i] = bytes[*offset + i];
+        i += 1;
     }
+    *offset += N;
+    output
+}
+
+#[doc(hidden)] // implementation detail of the `decode!` macro and `decode_padded()` methods
+pub const fn padding_len(len: usize, padded_len: usize) -> usize {
+    compile_assert!(
+        len <= padded_len,
+        "Output overflow: the input decodes to ", len => fmt::<usize>(),
+        " bytes, which exceeds the padded length ", padded_len => fmt::<usize>()
+    );
+    padded_len - len
 }
+
+#[doc(hidden)] // implementation detail of the `decode_array!` macro
+pub const fn assert_item_len(index: usize, len: usize, expected_len: usize) {
+    compile_assert!(
+        len == expected_len,
+        "Input #", index => fmt::<usize>(), " decodes to ", len => fmt::<usize>(),
+        " bytes, while the first input decodes to ", expected_len => fmt::<usize>(), " bytes"
+    );
+}
+
+/// Decodes a field of a record in a key / value test vector file, such as NIST CAVP `.rsp` files.
+/// See [`RspFile`](crate::RspFile) for the description of the file format.
+///
+/// The macro accepts the following comma-separated args:
+///
+/// 1. Decoder, same as for [`decode!`]
+/// 2. File contents, which must evaluate to `&[u8]` (e.g., an [`include_bytes!`] invocation)
+/// 3. Optional section name in the form `section = "L = 32"`
+/// 4. Record index in the form `record = 3`. Records are 0-based; if a section is specified,
+///    records are indexed within it.
+/// 5. Field name, e.g. `"MD"`.
+///
+/// The output of the macro is an array `[u8; N]` with the decoded bytes, same as for [`decode!`].
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode_field, Decoder};
+///
+/// const VECTORS: &[u8] = b"
+/// [L = 32]
+///
+/// Len = 8
+/// Msg = d3
+/// MD = 28969cdfa74a12c82f3bad960b0b000aca2ac329deea5c2328ebc6f2ba9802c1
+///
+/// Len = 16
+/// Msg = 11af
+/// MD = 5ca7133fa735326081558ac312c620eeca9970d1e70a4b95533d956f072d1f98
+/// ";
+/// // Would usually be used with `include_bytes!`
+/// const MSG: &[u8] = &decode_field!(Decoder::Hex, VECTORS, record = 1, "Msg");
+/// assert_eq!(MSG, [0x11, 0xaf]);
+/// const DIGEST: &[u8] =
+///     &decode_field!(Decoder::Hex, VECTORS, section = "L = 32", record = 0, "MD");
+/// assert_eq!(DIGEST.len(), 32);
+/// ```
+#[macro_export]
+macro_rules! decode_field {
+    (
+        $decoder:expr,
+        $bytes:expr,
+        $(section = $section:expr,)?
+        record = $record:expr,
+        $field:expr $(,)?
+    ) => {{
+        const __FILE: $crate::RspFile<'static> = $crate::RspFile::new($bytes)$(.section($section))?;
+        const __VALUE: &[u8] = __FILE.field($record, $field);
+        const __OUTPUT_LEN: usize =
+            __FILE.check_decoded_len($decoder.try_decode_len(__VALUE), $record, $field);
+        let mut __output = [0_u8; __OUTPUT_LEN];
+        $decoder.decode_into(__VALUE, &mut __output);
+        __output
+    }};
+}
+
+/// Decodes a string value in a JSON file, such as [Project Wycheproof] test vectors.
+/// See [`JsonFile`](crate::JsonFile) for the description of supported JSON paths.
+///
+/// The macro accepts three comma-separated args:
+///
+/// 1. Decoder, same as for [`decode!`]
+/// 2. File contents, which must evaluate to `&[u8]` (e.g., an [`include_bytes!`] invocation)
+/// 3. JSON path to the string value, e.g. `"testGroups[2].tests[5].msg"`.
+///
+/// The output of the macro is an array `[u8; N]` with the decoded bytes, same as for [`decode!`].
+/// If the path cannot be resolved or the value cannot be decoded, the error message
+/// will mention the path.
+///
+/// [Project Wycheproof]: https://github.com/C2SP/wycheproof
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode_json, Decoder};
+///
+/// const VECTORS: &[u8] = br#"{
+///   "testGroups": [{
+///     "tests": [
+///       { "tcId": 1, "msg": "", "sig": "" },
+///       { "tcId": 2, "msg": "78", "sig": "d4fbdb52bfa726b44d1786a8c0d171c3" }
+///     ]
+///   }]
+/// }"#;
+/// // Would usually be used with `include_bytes!`
+/// const MSG: &[u8] = &decode_json!(Decoder::Hex, VECTORS, "testGroups[0].tests[1].msg");
+/// assert_eq!(MSG, [0x78]);
+/// ```
+///
+/// Invalid values lead to a compilation error:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode_json, Decoder};
+/// const VECTORS: &[u8] = br#"{ "msg": "what?" }"#;
+/// const MSG: &[u8] = &decode_json!(Decoder::Hex, VECTORS, "msg");
+/// ```
+#[macro_export]
+macro_rules! decode_json {
+    ($decoder:expr, $bytes:expr, $path:expr $(,)?) => {{
+        const __FILE: $crate::JsonFile<'static> = $crate::JsonFile::new($bytes);
+        const __VALUE: &[u8] = __FILE.string($path);
+        const __OUTPUT_LEN: usize =
+            __FILE.check_decoded_len($decoder.try_decode_len(__VALUE), $path);
+        let mut __output = [0_u8; __OUTPUT_LEN];
+        $decoder.decode_into(__VALUE, &mut __output);
+        __output
+    }};
+}
+
+/// Implements `decode()`, `decode_len()`, `try_decode_len()` and `decode_into()` methods
+/// for a unit-struct decoder. The decoder must have an inherent
+/// `const fn try_decode_into(input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError>`
+/// method returning the decoded length, which may exceed the `output` length (the excessive bytes
+/// are not written). `invalid` describes invalid input in the generated docs.
+macro_rules! impl_decoding_methods {
+    ($decoder:ident, invalid = $invalid:literal) => {
+        impl $decoder {
+            /// Decodes `input` into a byte array.
+            ///
+            /// # Panics
+            ///
+            /// - Panics if the provided length is insufficient or too large for `input`.
+            #[doc = concat!("- Panics if `input` ", $invalid, ".")]
+            pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+                let mut bytes = [0_u8; N];
+                Self.decode_into(input, &mut bytes);
+                bytes
+            }
+
+            /// Computes the length of the decoded `input` in bytes.
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if `input` ", $invalid, ".")]
+            pub const fn decode_len(&self, input: &[u8]) -> usize {
+                match self.try_decode_len(input) {
+                    Ok(len) => len,
+                    Err(err) => err.panic_in(input),
+                }
+            }
+
+            /// Computes the length of the decoded `input` in bytes.
+            ///
+            /// # Errors
+            ///
+            #[doc = concat!("Returns an error if `input` ", $invalid, ".")]
+            pub const fn try_decode_len(&self, input: &[u8]) -> Result<usize, DecodeError> {
+                Self::try_decode_into(input, &mut [])
+            }
+
+            /// Decodes `input` into the `output` buffer.
+            ///
+            /// # Panics
+            ///
+            /// - Panics if `output` length is not equal to the [decoded length](Self::decode_len())
+            ///   of `input`.
+            #[doc = concat!("- Panics if `input` ", $invalid, ".")]
+            pub const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+                use compile_fmt::{compile_assert, fmt};
+
+                let out_len = match Self::try_decode_into(input, output) {
+                    Ok(len) => len,
+                    Err(err) => err.panic_in(input),
+                };
+                compile_assert!(
+                    out_len == output.len(),
+                    "Output length mismatch: the input decodes to ", out_len => fmt::<usize>(),
+                    " bytes, while type inference implies ", output.len() => fmt::<usize>(), ". \
+                    Either fix the input or change the output buffer length correspondingly"
+                );
+            }
+        }
+    };
+}
+
+pub(crate) use impl_decoding_methods;
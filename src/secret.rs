@@ -0,0 +1,85 @@
+//! Wiping decoded secrets.
+
+use core::fmt;
+
+use zeroize::Zeroize;
+
+use crate::{
+    decoder::{check_output_len, DecoderState},
+    wrappers::Skipper,
+};
+
+/// Secret value (e.g., a private key decoded in runtime) that is wiped on drop.
+///
+/// The `Debug` implementation does not output the wrapped value. Note that wiping is
+/// best-effort: `decode_secret()` methods return secrets by value, and this or any other move
+/// of a `Secret` may leave copies of the value in memory that are not wiped.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{Decoder, Secret};
+/// # fn read_secret() -> String { "9e55d1e1aa1f455b8baad9fdf975503655f8b359d542fa7e4ce84106d625b352".into() }
+/// let secret_hex: String = read_secret();
+/// let secret_key: Secret<[u8; 32]> =
+///     Decoder::Hex.constant_time().decode_secret(secret_hex.as_bytes());
+/// assert_eq!(secret_key.expose_secret()[0], 0x9e);
+/// assert_eq!(format!("{secret_key:?}"), "Secret(_)");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("Secret(_)")
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> Secret<T> {
+    /// Wraps the provided value.
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Exposes the wrapped value.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Decodes `input` into a secret, wiping the decoder state afterwards. If decoding fails,
+/// the partially decoded output is wiped when the secret is dropped during unwinding.
+pub(crate) fn decode_secret<const N: usize>(
+    mut state: DecoderState,
+    input: &[u8],
+    skipper: Option<Skipper>,
+) -> Secret<[u8; N]> {
+    let mut secret = Secret([0_u8; N]);
+    let result = state.try_decode_into(input, skipper, &mut secret.0);
+    let leftover_error = if state.is_final() {
+        None
+    } else {
+        Some(state.secret_leftover_error(input.len()))
+    };
+    let bits_per_char = state.bits_per_char();
+    let pending_bits = state.pending_bits();
+    state.wipe();
+
+    match result {
+        // The input is not used to locate the overflow since it may be revealed in the panic message.
+        Ok(out_len) => check_output_len(out_len, N, bits_per_char, pending_bits, None),
+        // Neither the invalid char nor the input excerpt are included into the panic message
+        // since they may contain secret data.
+        Err(err) => err.redact_char().panic(),
+    }
+    if let Some(err) = leftover_error {
+        err.panic();
+    }
+    secret
+}
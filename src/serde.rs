@@ -0,0 +1,259 @@
+//! `serde` helpers for (de)serializing byte arrays as strings.
+//!
+//! The modules / types in this module are intended to be used with the `#[serde(with = ...)]`
+//! attribute on `[u8; N]` fields. Deserialization uses the same rules as the corresponding
+//! [`Decoder`]: e.g., whitespace is not allowed, and base64 padding is optional.
+//! Serialization produces strings that are accepted by the decoder.
+//!
+//! # Examples
+//!
+//! ```
+//! use const_decoder::{decode, serde::CustomEncoding, Decoder, Encoding};
+//! # use serde::{Deserialize, Serialize};
+//!
+//! struct Bech32;
+//!
+//! impl CustomEncoding for Bech32 {
+//!     const ENCODING: Encoding = Encoding::new("qpzry9x8gf2tvdw0s3jn54khce6mua7l");
+//! }
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "const_decoder::serde::hex")]
+//!     secret_key: [u8; 32],
+//!     #[serde(with = "const_decoder::serde::base64")]
+//!     nonce: [u8; 12],
+//!     #[serde(with = "const_decoder::serde::Custom::<Bech32>")]
+//!     address: [u8; 20],
+//! }
+//!
+//! let json = r#"{
+//!     "secret_key": "9e55d1e1aa1f455b8baad9fdf975503655f8b359d542fa7e4ce84106d625b352",
+//!     "nonce": "AAECAwQFBgcICQoL",
+//!     "address": "w508d6qejxtdg4y5r3zarvary0c5xw7k"
+//! }"#;
+//! let config: Config = serde_json::from_str(json)?;
+//! assert_eq!(config.nonce, decode!(Decoder::Base64, b"AAECAwQFBgcICQoL"));
+//!
+//! let roundtrip: Config = serde_json::from_str(&serde_json::to_string(&config)?)?;
+//! assert_eq!(roundtrip, config);
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{de, Deserializer, Serializer};
+
+use crate::{
+    decoder::{Decoder, Encoding},
+    encoder::EncoderState,
+};
+
+/// Encoding with a custom alphabet used in [`Custom`].
+pub trait CustomEncoding {
+    /// Encoding used for (de)serialization.
+    const ENCODING: Encoding;
+}
+
+/// Byte array encoded as a string.
+struct Encoded<'a> {
+    bytes: &'a [u8],
+    encoding: Encoding,
+    padding: bool,
+}
+
+impl fmt::Display for Encoded<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut write_char = |ch: u8| fmt::Write::write_char(formatter, ch.into());
+        let mut state = EncoderState::new(self.encoding);
+        for &byte in self.bytes {
+            state.update(byte, &mut write_char)?;
+        }
+        state.finish(self.padding, write_char)
+    }
+}
+
+struct ArrayVisitor<const N: usize> {
+    decoder: Decoder,
+    encoding_name: &'static str,
+}
+
+impl<const N: usize> de::Visitor<'_> for ArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} string encoding {N} bytes",
+            self.encoding_name
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let mut bytes = [0_u8; N];
+        let mut state = self.decoder.new_state(false);
+        let out_len = state
+            .try_decode_into(value.as_bytes(), None, &mut bytes)
+            .map_err(E::custom)?;
+        if out_len != N {
+            return Err(E::invalid_length(out_len, &self));
+        }
+        if !state.is_final() {
+            return Err(E::custom(state.leftover_error(value.len())));
+        }
+        Ok(bytes)
+    }
+}
+
+fn serialize<S: Serializer>(
+    bytes: &[u8],
+    encoding: Encoding,
+    padding: bool,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&Encoded {
+        bytes,
+        encoding,
+        padding,
+    })
+}
+
+fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+    decoder: Decoder,
+    encoding_name: &'static str,
+) -> Result<[u8; N], D::Error> {
+    deserializer.deserialize_str(ArrayVisitor {
+        decoder,
+        encoding_name,
+    })
+}
+
+/// (De)serialization of byte arrays as hex strings. Serialization uses lowercase digits;
+/// deserialization accepts both lowercase and uppercase digits, as [`Decoder::Hex`].
+pub mod hex {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::decoder::{Decoder, Encoding};
+
+    /// Serializes a byte array as a hex string.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from the serializer.
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize(bytes, Encoding::HEX, false, serializer)
+    }
+
+    /// Deserializes a byte array from a hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid hex, or if it decodes to an unexpected
+    /// number of bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        super::deserialize(deserializer, Decoder::Hex, "hex")
+    }
+}
+
+/// (De)serialization of byte arrays as base64 strings using the standard alphabet.
+/// Serialization adds padding; deserialization accepts strings with or without padding,
+/// as [`Decoder::Base64`].
+pub mod base64 {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::decoder::{Decoder, Encoding};
+
+    /// Serializes a byte array as a padded base64 string.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from the serializer.
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize(bytes, Encoding::BASE64, true, serializer)
+    }
+
+    /// Deserializes a byte array from a base64 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid base64, or if it decodes to an unexpected
+    /// number of bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        super::deserialize(deserializer, Decoder::Base64, "base64")
+    }
+}
+
+/// (De)serialization of byte arrays as base64 strings using the URL-safe alphabet.
+/// Serialization does not add padding; deserialization accepts strings with or without padding,
+/// as [`Decoder::Base64Url`].
+pub mod base64url {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::decoder::{Decoder, Encoding};
+
+    /// Serializes a byte array as an unpadded URL-safe base64 string.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from the serializer.
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize(bytes, Encoding::BASE64_URL, false, serializer)
+    }
+
+    /// Deserializes a byte array from a URL-safe base64 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid base64, or if it decodes to an unexpected
+    /// number of bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        super::deserialize(deserializer, Decoder::Base64Url, "base64url")
+    }
+}
+
+/// (De)serialization of byte arrays as strings with a [custom encoding](CustomEncoding).
+/// No padding is added during serialization. Use as `#[serde(with = "Custom::<E>")]`.
+#[derive(Debug)]
+pub struct Custom<E>(PhantomData<E>);
+
+impl<E: CustomEncoding> Custom<E> {
+    /// Serializes a byte array as a string.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from the serializer.
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize(bytes, E::ENCODING, false, serializer)
+    }
+
+    /// Deserializes a byte array from a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string contains chars outside the alphabet, or if it decodes
+    /// to an unexpected number of bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        deserialize(deserializer, Decoder::Custom(E::ENCODING), "custom-encoded")
+    }
+}
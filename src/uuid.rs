@@ -0,0 +1,258 @@
+//! Decoder for UUIDs.
+
+use compile_fmt::{compile_assert, fmt};
+
+use crate::{
+    decoder::HexDecoderState,
+    error::{DecodeError, UuidError},
+};
+
+/// Decoder for [UUIDs](https://www.rfc-editor.org/rfc/rfc9562) into 16-byte arrays.
+///
+/// The following forms are supported:
+///
+/// - Hyphenated: `67e55044-10b1-426f-9247-bb680e5fe0c8`
+/// - Simple: `67e5504410b1426f9247bb680e5fe0c8`
+/// - Braced: `{67e55044-10b1-426f-9247-bb680e5fe0c8}` (the braced UUID may be simple as well)
+/// - URN: `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`
+///
+/// Hex digits are case-insensitive. Unlike with [`SkipWhitespace`](crate::SkipWhitespace) or
+/// other skipping decoders, hyphen positions are validated: the hyphenated form must consist of
+/// 8-4-4-4-12 hex digit groups.
+///
+/// By default, the version and variant of the UUID are not checked. Use [`Self::with_version()`]
+/// and [`Self::with_rfc_variant()`] to check them.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder::{decode, Uuid};
+///
+/// const ID: [u8; 16] = Uuid::new().decode(b"67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// assert_eq!(ID[..4], [0x67, 0xe5, 0x50, 0x44]);
+/// const URN: [u8; 16] = Uuid::new().decode(b"urn:uuid:67E55044-10B1-426F-9247-BB680E5FE0C8");
+/// assert_eq!(URN, ID);
+///
+/// // Checking the version and variant.
+/// const V4: Uuid = Uuid::new().with_version(4).with_rfc_variant();
+/// const BRACED: [u8; 16] = V4.decode(b"{67e5504410b1426f9247bb680e5fe0c8}");
+/// assert_eq!(BRACED, ID);
+/// const SLICE: &[u8] = &decode!(V4, b"67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// assert_eq!(SLICE, ID);
+///
+/// // Special UUIDs have no RFC 9562 variant, but can be checked for the version.
+/// const NIL: [u8; 16] = Uuid::new()
+///     .with_version(0)
+///     .decode(b"00000000-0000-0000-0000-000000000000");
+/// assert_eq!(NIL, [0; 16]);
+/// ```
+///
+/// Malformed grouping is rejected:
+///
+/// ```compile_fail
+/// # use const_decoder::Uuid;
+/// const ID: [u8; 16] = Uuid::new().decode(b"67e5504-410b1-426f-9247-bb680e5fe0c8");
+/// // panics: Unexpected '-' at position 7 of the UUID; hyphens must separate...
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uuid {
+    version: Option<u8>,
+    rfc_variant: bool,
+}
+
+impl Uuid {
+    const URN_PREFIX: &'static [u8] = b"urn:uuid:";
+    const HYPHEN_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+
+    /// Creates a decoder accepting UUIDs with any version and variant.
+    pub const fn new() -> Self {
+        Self {
+            version: None,
+            rfc_variant: false,
+        }
+    }
+
+    /// Requires decoded UUIDs to have the specified version. The variant is not checked;
+    /// use [`Self::with_rfc_variant()`] to check it as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` is greater than 15.
+    #[must_use]
+    pub const fn with_version(mut self, version: u8) -> Self {
+        compile_assert!(
+            version < 16,
+            "UUID version must be in 0..=15; got ", version => fmt::<u8>()
+        );
+        self.version = Some(version);
+        self
+    }
+
+    /// Requires decoded UUIDs to have the variant specified in RFC 9562 (i.e., the variant nibble
+    /// must be one of `8`, `9`, `a` or `b`). Note that the nil and max UUIDs do not have
+    /// this variant.
+    #[must_use]
+    pub const fn with_rfc_variant(mut self) -> Self {
+        self.rfc_variant = true;
+        self
+    }
+
+    const fn has_prefix(input: &[u8], prefix: &[u8]) -> bool {
+        if input.len() < prefix.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < prefix.len() {
+            if input[i].to_ascii_lowercase() != prefix[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns the start and end positions of the UUID body (i.e., hex digits and hyphens).
+    const fn body_range(input: &[u8]) -> Result<(usize, usize), DecodeError> {
+        if Self::has_prefix(input, Self::URN_PREFIX) {
+            Ok((Self::URN_PREFIX.len(), input.len()))
+        } else if !input.is_empty() && input[0] == b'{' {
+            if input.len() < 2 || input[input.len() - 1] != b'}' {
+                return Err(DecodeError::invalid_uuid(UuidError::UnclosedBrace, 0));
+            }
+            Ok((1, input.len() - 1))
+        } else {
+            Ok((0, input.len()))
+        }
+    }
+
+    /// Tries to decode `input` into a UUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is not a valid UUID, or if it doesn't have the expected
+    /// version / variant.
+    pub const fn try_decode(self, input: &[u8]) -> Result<[u8; 16], DecodeError> {
+        let (start, end) = match Self::body_range(input) {
+            Ok(range) => range,
+            Err(err) => return Err(err),
+        };
+        let hyphenated = match end - start {
+            32 => false,
+            36 => true,
+            len => {
+                return Err(DecodeError::invalid_uuid(
+                    UuidError::InvalidLength(len),
+                    start,
+                ))
+            }
+        };
+
+        let mut bytes = [0_u8; 16];
+        let mut digit_count = 0;
+        let mut pos = start;
+        while pos < end {
+            let ch = input[pos];
+            let is_hyphen_pos = hyphenated && {
+                let offset = pos - start;
+                let mut i = 0;
+                while i < Self::HYPHEN_POSITIONS.len() && Self::HYPHEN_POSITIONS[i] != offset {
+                    i += 1;
+                }
+                i < Self::HYPHEN_POSITIONS.len()
+            };
+
+            if is_hyphen_pos {
+                if ch != b'-' {
+                    return Err(DecodeError::invalid_uuid(UuidError::MissingHyphen, pos));
+                }
+            } else if ch == b'-' {
+                return Err(DecodeError::invalid_uuid(UuidError::MisplacedHyphen, pos));
+            } else {
+                let digit = match HexDecoderState::byte_value(ch) {
+                    Ok(digit) => digit,
+                    Err(err) => return Err(DecodeError::invalid_char(err, pos)),
+                };
+                bytes[digit_count / 2] |= if digit_count % 2 == 0 {
+                    digit << 4
+                } else {
+                    digit
+                };
+                digit_count += 1;
+            }
+            pos += 1;
+        }
+
+        // Positions of the version and variant nibbles relative to the body start.
+        let (version_pos, variant_pos) = if hyphenated { (14, 19) } else { (12, 16) };
+        if let Some(expected) = self.version {
+            let actual = bytes[6] >> 4;
+            if actual != expected {
+                let err = UuidError::Version { actual, expected };
+                return Err(DecodeError::invalid_uuid(err, start + version_pos));
+            }
+        }
+        if self.rfc_variant && bytes[8] & 0xc0 != 0x80 {
+            let err = UuidError::Variant;
+            return Err(DecodeError::invalid_uuid(err, start + variant_pos));
+        }
+        Ok(bytes)
+    }
+
+    /// Decodes `input` into a UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not a valid UUID, or if it doesn't have the expected
+    /// version / variant.
+    pub const fn decode(self, input: &[u8]) -> [u8; 16] {
+        match self.try_decode(input) {
+            Ok(bytes) => bytes,
+            Err(err) => err.panic_in(input),
+        }
+    }
+
+    /// Computes the length of the decoded `input` in bytes, which is always 16.
+    /// This method allows using `Uuid` with [`decode!`](crate::decode) and similar macros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is invalid.
+    pub const fn decode_len(&self, input: &[u8]) -> usize {
+        match self.try_decode_len(input) {
+            Ok(len) => len,
+            Err(err) => err.panic_in(input),
+        }
+    }
+
+    /// Computes the length of the decoded `input` in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid.
+    pub const fn try_decode_len(&self, input: &[u8]) -> Result<usize, DecodeError> {
+        match self.try_decode(input) {
+            Ok(bytes) => Ok(bytes.len()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Decodes `input` into the `output` buffer.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `output` length is not equal to 16.
+    /// - Panics if `input` is invalid.
+    pub const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+        compile_assert!(
+            output.len() == 16,
+            "Output length mismatch: UUIDs decode to 16 bytes, while type inference implies ",
+            output.len() => fmt::<usize>()
+        );
+        let bytes = self.decode(input);
+        let mut i = 0;
+        while i < 16 {
+            output[i] = bytes[i];
+            i += 1;
+        }
+    }
+}
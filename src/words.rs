@@ -0,0 +1,107 @@
+//! Decoding into integer words.
+
+use compile_fmt::{compile_assert, fmt};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Unsigned integer type that can be used as a word in [`Decoder::decode_words()`].
+///
+/// This trait is sealed; it is implemented for `u16`, `u32`, `u64` and `u128`.
+///
+/// [`Decoder::decode_words()`]: crate::Decoder::decode_words()
+pub trait Word: Copy + sealed::Sealed {
+    #[doc(hidden)] // implementation detail
+    const ZERO: Self;
+    #[doc(hidden)] // implementation detail
+    const BYTES: usize;
+}
+
+macro_rules! impl_word {
+    ($($int:ty),+) => {
+        $(
+        impl sealed::Sealed for $int {}
+
+        impl Word for $int {
+            const ZERO: Self = 0;
+            const BYTES: usize = core::mem::size_of::<$int>();
+        }
+        )+
+    };
+}
+
+impl_word!(u16, u32, u64, u128);
+
+/// Byte order of words decoded by [`Decoder::decode_words()`]. Implemented by [`BigEndian`]
+/// and [`LittleEndian`] markers.
+///
+/// [`Decoder::decode_words()`]: crate::Decoder::decode_words()
+pub trait ByteOrder: sealed::Sealed {
+    #[doc(hidden)] // implementation detail
+    const IS_BIG_ENDIAN: bool;
+}
+
+/// Big-endian byte order: the most significant byte of a word goes first.
+#[derive(Debug, Clone, Copy)]
+pub struct BigEndian;
+
+impl sealed::Sealed for BigEndian {}
+
+impl ByteOrder for BigEndian {
+    const IS_BIG_ENDIAN: bool = true;
+}
+
+/// Little-endian byte order: the least significant byte of a word goes first.
+#[derive(Debug, Clone, Copy)]
+pub struct LittleEndian;
+
+impl sealed::Sealed for LittleEndian {}
+
+impl ByteOrder for LittleEndian {
+    const IS_BIG_ENDIAN: bool = false;
+}
+
+#[doc(hidden)] // implementation detail of the `decode_words!` macro
+pub const fn word_count<W: Word>(byte_len: usize) -> usize {
+    compile_assert!(
+        byte_len % W::BYTES == 0,
+        "The input decodes to ", byte_len => fmt::<usize>(), " bytes, which is not \
+        a multiple of the word size (", W::BYTES => fmt::<usize>(), " bytes)"
+    );
+    byte_len / W::BYTES
+}
+
+#[doc(hidden)] // implementation detail of the `decode_words!` macro
+pub const fn words_as_bytes_mut<W: Word>(words: &mut [W]) -> &mut [u8] {
+    let len = words.len() * W::BYTES;
+    // SAFETY: `W` is a primitive unsigned int (guaranteed by `Word` being sealed), so it has
+    // no padding, and any bit pattern is valid for it.
+    unsafe { core::slice::from_raw_parts_mut(words.as_mut_ptr().cast::<u8>(), len) }
+}
+
+/// Converts words decoded into `bytes` (obtained via [`words_as_bytes_mut()`]) from
+/// the byte order `E` to the native one.
+#[doc(hidden)] // implementation detail of the `decode_words!` macro
+pub const fn convert_byte_order<W: Word, E: ByteOrder>(bytes: &mut [u8]) {
+    // Words are read from memory in the native byte order, so we need to reverse bytes
+    // in each word if the requested order differs from it.
+    if E::IS_BIG_ENDIAN != cfg!(target_endian = "big") {
+        reverse_words(bytes, W::BYTES);
+    }
+}
+
+/// Reverses bytes in each `word_len`-byte chunk of `bytes`.
+const fn reverse_words(bytes: &mut [u8], word_len: usize) {
+    let mut start = 0;
+    while start < bytes.len() {
+        let mut i = 0;
+        while i < word_len / 2 {
+            let tmp = bytes[start + i];
+            bytes[start + i] = bytes[start + word_len - 1 - i];
+            bytes[start + word_len - 1 - i] = tmp;
+            i += 1;
+        }
+        start += word_len;
+    }
+}
@@ -1,6 +1,13 @@
 //! Decoder wrappers.
 
-use crate::decoder::Decoder;
+#[cfg(feature = "zeroize")]
+use crate::secret::{decode_secret, Secret};
+use crate::{
+    decoder::{Decoder, DecoderState},
+    error::DecodeError,
+    streaming::StreamingDecoder,
+    words::{ByteOrder, Word},
+};
 
 /// [`Decoder`] wrapper that skips whitespace during decoding instead of panicking.
 ///
@@ -29,6 +36,228 @@ impl SkipWhitespace {
     pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
         self.0.do_decode(input, Some(Skipper::Whitespace))
     }
+
+    /// Decodes `input` into a byte array, right-aligning the decoded bytes and filling
+    /// the leading bytes with zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_padded()`].
+    pub const fn decode_padded<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        self.0.do_decode_padded(input, Some(Skipper::Whitespace))
+    }
+
+    /// Decodes exactly `N` bytes from the start of `input`, skipping whitespace. Returns
+    /// the decoded bytes and the position in `input` after the last consumed char; whitespace
+    /// after this char is not consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_prefix()`].
+    pub const fn decode_prefix<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        self.0
+            .do_decode_prefix(input, Some(Skipper::Whitespace), None)
+    }
+
+    /// Decodes exactly `N` bytes from the start of `input` followed by the `separator` byte,
+    /// skipping whitespace. Returns the decoded bytes and the position in `input`
+    /// after the separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_prefix_until()`].
+    pub const fn decode_prefix_until<const N: usize>(
+        self,
+        input: &[u8],
+        separator: u8,
+    ) -> ([u8; N], usize) {
+        self.0
+            .do_decode_prefix(input, Some(Skipper::Whitespace), Some(separator))
+    }
+
+    /// Computes the length of the decoded `input` in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is invalid (e.g., contains invalid chars).
+    pub const fn decode_len(&self, input: &[u8]) -> usize {
+        self.0.do_decode_len(input, Some(Skipper::Whitespace))
+    }
+
+    /// Computes the length of the decoded `input` in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid (e.g., contains invalid chars).
+    pub const fn try_decode_len(&self, input: &[u8]) -> Result<usize, DecodeError> {
+        self.0.do_try_decode_len(input, Some(Skipper::Whitespace))
+    }
+
+    /// Decodes `input` into the `output` buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_into()`].
+    pub const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+        self.0
+            .do_decode_into(input, Some(Skipper::Whitespace), output);
+    }
+
+    /// Creates a [`StreamingDecoder`] for decoding chunked input.
+    pub const fn streaming(self) -> StreamingDecoder {
+        StreamingDecoder::new(self.0.new_state(false), Some(Skipper::Whitespace))
+    }
+
+    /// Decodes `input` into a byte array wrapped in a [`Secret`].
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::decode()`].
+    #[cfg(feature = "zeroize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+    pub fn decode_secret<const N: usize>(self, input: &[u8]) -> Secret<[u8; N]> {
+        decode_secret(self.0.new_state(false), input, Some(Skipper::Whitespace))
+    }
+
+    /// Decodes `input` into an array of bits, skipping whitespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_bits()`].
+    pub const fn decode_bits<const N: usize>(self, input: &[u8]) -> [bool; N] {
+        self.0.do_decode_bits(input, Some(Skipper::Whitespace))
+    }
+
+    /// Decodes `input` into an array of words with the specified byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_words()`].
+    pub const fn decode_words<W: Word, const N: usize, E: ByteOrder>(self, input: &[u8]) -> [W; N] {
+        self.0
+            .do_decode_words::<W, N, E>(input, Some(Skipper::Whitespace))
+    }
+
+    /// Decodes `input` into a big-endian `u64`, skipping whitespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_u64()`].
+    pub const fn decode_u64(self, input: &[u8]) -> u64 {
+        u64::from_be_bytes(self.decode(input))
+    }
+
+    /// Decodes `input` into a big-endian `u128`, skipping whitespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_u128()`].
+    pub const fn decode_u128(self, input: &[u8]) -> u128 {
+        u128::from_be_bytes(self.decode(input))
+    }
+}
+
+/// [`Decoder`] wrapper that processes input chars in constant time, i.e., without branching
+/// on their values or using them as indexes into lookup tables. This is useful when decoding
+/// secrets (e.g., private keys) in runtime.
+///
+/// Hex digits are decoded using arithmetic range checks; chars in base64 and custom encodings
+/// are compared with all chars in the alphabet. Decoded bytes are the same as for the wrapped
+/// decoder. Only the validity of the input and the position of base64 padding are not protected.
+///
+/// Unlike [`SkipWhitespace`] and [`Pem`], this wrapper only decodes into byte arrays and buffers.
+/// Padded, prefix, bit and word decoding are intended for compile-time constants and are not
+/// implemented in constant time; to get words or integers from a secret, decode it into
+/// a byte array and convert the array.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::Decoder;
+/// # fn read_secret() -> String { "9e55d1e1aa1f455b8baad9fdf975503655f8b359d542fa7e4ce84106d625b352".into() }
+/// let secret_hex: String = read_secret();
+/// let secret_key: [u8; 32] = Decoder::Hex.constant_time().decode(secret_hex.as_bytes());
+/// # assert_eq!(secret_key[0], 0x9e);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConstantTime(pub Decoder);
+
+impl ConstantTime {
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid chars.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let mut bytes = [0_u8; N];
+        self.decode_into(input, &mut bytes);
+        bytes
+    }
+
+    /// Computes the length of the decoded `input` in bytes. The length computation
+    /// is not constant-time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is invalid (e.g., contains invalid chars). Unlike with other decoders,
+    /// the panic message does not include the invalid char or an excerpt of `input`.
+    pub const fn decode_len(&self, input: &[u8]) -> usize {
+        match self.try_decode_len(input) {
+            Ok(len) => len,
+            Err(err) => err.panic(),
+        }
+    }
+
+    /// Computes the length of the decoded `input` in bytes. The length computation
+    /// is not constant-time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid (e.g., contains invalid chars). The error
+    /// does not reveal invalid chars or partially decoded data.
+    pub const fn try_decode_len(&self, input: &[u8]) -> Result<usize, DecodeError> {
+        let mut state = self.0.new_state(true);
+        let len = match state.try_decode_into(input, None, &mut []) {
+            Ok(len) => len,
+            Err(err) => return Err(err),
+        };
+        if state.is_final() {
+            Ok(len)
+        } else {
+            Err(state.leftover_error(input.len()))
+        }
+    }
+
+    /// Decodes `input` into the `output` buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_into()`]. Unlike with other decoders,
+    /// panic messages do not include invalid chars, an excerpt of `input` or the partially
+    /// decoded data.
+    pub const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+        self.0
+            .new_state(true)
+            .decode_secret_into(input, None, output);
+    }
+
+    /// Creates a [`StreamingDecoder`] processing input chars in constant time.
+    pub const fn streaming(self) -> StreamingDecoder {
+        StreamingDecoder::new(self.0.new_state(true), None)
+    }
+
+    /// Decodes `input` into a byte array wrapped in a [`Secret`], which wipes the decoded bytes
+    /// on drop. If decoding fails midway, the partially decoded data is wiped before panicking.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::decode()`].
+    #[cfg(feature = "zeroize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+    pub fn decode_secret<const N: usize>(self, input: &[u8]) -> Secret<[u8; N]> {
+        decode_secret(self.0.new_state(true), input, None)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -96,4 +325,119 @@ impl Pem {
     pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
         Decoder::Base64.do_decode(input, Some(Skipper::Pem))
     }
+
+    /// Decodes `input` into a byte array, right-aligning the decoded bytes and filling
+    /// the leading bytes with zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_padded()`].
+    pub const fn decode_padded<const N: usize>(input: &[u8]) -> [u8; N] {
+        Decoder::Base64.do_decode_padded(input, Some(Skipper::Pem))
+    }
+
+    /// Decodes exactly `N` bytes from the start of `input`, skipping whitespace and
+    /// header / trailer lines. Returns the decoded bytes and the position in `input`
+    /// after the last consumed char.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_prefix()`].
+    pub const fn decode_prefix<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        Decoder::Base64.do_decode_prefix(input, Some(Skipper::Pem), None)
+    }
+
+    /// Decodes exactly `N` bytes from the start of `input` followed by the `separator` byte,
+    /// skipping whitespace and header / trailer lines. Returns the decoded bytes
+    /// and the position in `input` after the separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_prefix_until()`].
+    pub const fn decode_prefix_until<const N: usize>(
+        input: &[u8],
+        separator: u8,
+    ) -> ([u8; N], usize) {
+        Decoder::Base64.do_decode_prefix(input, Some(Skipper::Pem), Some(separator))
+    }
+
+    /// Computes the length of the decoded `input` in bytes. Unlike [`Self::decode()`], this method
+    /// takes `&self` so that `Pem` can be used with [`decode!`](crate::decode) and similar macros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is invalid (e.g., contains invalid chars).
+    pub const fn decode_len(&self, input: &[u8]) -> usize {
+        Decoder::Base64.do_decode_len(input, Some(Skipper::Pem))
+    }
+
+    /// Computes the length of the decoded `input` in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid (e.g., contains invalid chars).
+    pub const fn try_decode_len(&self, input: &[u8]) -> Result<usize, DecodeError> {
+        Decoder::Base64.do_try_decode_len(input, Some(Skipper::Pem))
+    }
+
+    /// Decodes `input` into the `output` buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_into()`].
+    pub const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+        Decoder::Base64.do_decode_into(input, Some(Skipper::Pem), output);
+    }
+
+    /// Creates a [`StreamingDecoder`] for decoding chunked input.
+    pub const fn streaming() -> StreamingDecoder {
+        StreamingDecoder::new(DecoderState::new_pem(), Some(Skipper::Pem))
+    }
+
+    /// Decodes `input` into a byte array wrapped in a [`Secret`].
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::decode()`].
+    #[cfg(feature = "zeroize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+    pub fn decode_secret<const N: usize>(input: &[u8]) -> Secret<[u8; N]> {
+        decode_secret(DecoderState::new_pem(), input, Some(Skipper::Pem))
+    }
+
+    /// Decodes `input` into an array of bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_bits()`].
+    pub const fn decode_bits<const N: usize>(input: &[u8]) -> [bool; N] {
+        Decoder::Base64.do_decode_bits(input, Some(Skipper::Pem))
+    }
+
+    /// Decodes `input` into an array of words with the specified byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_words()`].
+    pub const fn decode_words<W: Word, const N: usize, E: ByteOrder>(input: &[u8]) -> [W; N] {
+        Decoder::Base64.do_decode_words::<W, N, E>(input, Some(Skipper::Pem))
+    }
+
+    /// Decodes `input` into a big-endian `u64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_u64()`].
+    pub const fn decode_u64(input: &[u8]) -> u64 {
+        u64::from_be_bytes(Self::decode(input))
+    }
+
+    /// Decodes `input` into a big-endian `u128`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Decoder::decode_u128()`].
+    pub const fn decode_u128(input: &[u8]) -> u128 {
+        u128::from_be_bytes(Self::decode(input))
+    }
 }
@@ -3,8 +3,12 @@ use base64::{
     Engine as _,
 };
 use bech32::{Bech32, Hrp};
-use const_decoder::{decode, Decoder, Pem};
-use rand::RngCore;
+use const_decoder::{
+    decode, decode_array, decode_field, decode_json, decode_words, BigEndian, Decoder, Pem,
+};
+use rand::{Rng, RngCore};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
 
 #[test]
 fn reading_from_file_works() {
@@ -15,6 +19,146 @@ fn reading_from_file_works() {
     assert_eq!(CERT, parsed.contents());
 }
 
+#[test]
+fn streaming_decoding_of_file() {
+    const RAW_INPUT: &[u8] = include_bytes!("certificate.crt");
+    const CERT: &[u8] = &decode!(Pem, RAW_INPUT);
+
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        let mut decoder = Pem::streaming();
+        let mut output = vec![];
+        let mut remaining = RAW_INPUT;
+        while !remaining.is_empty() {
+            let chunk_len = rng.random_range(1..=remaining.len().min(100));
+            let (chunk, rest) = remaining.split_at(chunk_len);
+            remaining = rest;
+
+            let mut buffer = vec![0; decoder.max_output_len(chunk.len())];
+            let written = decoder.update(chunk, &mut buffer).unwrap();
+            output.extend_from_slice(&buffer[..written]);
+        }
+        decoder.finish().unwrap();
+        assert_eq!(output, CERT);
+    }
+}
+
+#[test]
+fn pinning_digest_of_file() {
+    const CERT: &[u8] = &decode!(
+        Pem,
+        include_bytes!("certificate.crt"),
+        sha256 = "320f91a3cf80eef533c273088698321403fee1299e168d1cfed2f93cea64d04a",
+    );
+    assert_eq!(const_decoder::sha256(CERT), *Sha256::digest(CERT));
+}
+
+#[test]
+fn digests_mini_fuzz() {
+    let mut rng = rand::rng();
+    for _ in 0..1_000 {
+        let len = rng.random_range(0..600);
+        let mut bytes = vec![0_u8; len];
+        rng.fill_bytes(&mut bytes);
+
+        assert_eq!(const_decoder::sha1(&bytes), *Sha1::digest(&bytes));
+        assert_eq!(const_decoder::sha256(&bytes), *Sha256::digest(&bytes));
+        assert_eq!(const_decoder::sha512(&bytes), *Sha512::digest(&bytes));
+    }
+}
+
+#[test]
+fn concatenating_file_with_literal() {
+    const RAW_INPUT: &[u8] = include_bytes!("certificate.crt");
+    const PREFIXED_CERT: &[u8] = &decode!(Decoder::Hex => b"c0ffee", Pem => RAW_INPUT);
+
+    let parsed = pem::parse(RAW_INPUT).unwrap();
+    assert_eq!(PREFIXED_CERT[..3], [0xc0, 0xff, 0xee]);
+    assert_eq!(PREFIXED_CERT[3..], *parsed.contents());
+}
+
+/// Custom decoder for the `Len` fields in `.rsp` files, which are decimal bit lengths.
+/// Decodes them into a 4-byte big-endian byte length.
+struct BitLength;
+
+impl BitLength {
+    const fn parse(input: &[u8]) -> u32 {
+        let mut value = 0_u32;
+        let mut i = 0;
+        while i < input.len() {
+            assert!(input[i].is_ascii_digit(), "Bit length must be decimal");
+            value = value * 10 + (input[i] - b'0') as u32;
+            i += 1;
+        }
+        assert!(value % 8 == 0, "Bit length must be divisible by 8");
+        value / 8
+    }
+
+    const fn decode_len(&self, input: &[u8]) -> usize {
+        Self::parse(input); // validates the input
+        4
+    }
+
+    const fn try_decode_len(&self, input: &[u8]) -> Result<usize, const_decoder::DecodeError> {
+        Ok(self.decode_len(input))
+    }
+
+    const fn decode_into(&self, input: &[u8], output: &mut [u8]) {
+        let bytes = Self::parse(input).to_be_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            output[i] = bytes[i];
+            i += 1;
+        }
+    }
+}
+
+#[test]
+fn using_custom_decoder_with_macros() {
+    const VECTORS: &[u8] = include_bytes!("sha256_short_msg.rsp");
+    const LEN: [u8; 4] = decode_field!(BitLength, VECTORS, record = 3, "Len");
+    const LEN_WORDS: [u32; 1] = decode_words!(BitLength, b"24", u32, BigEndian);
+    const LENS: [[u8; 4]; 2] = decode_array!(BitLength, [b"8", b"256"]);
+    const JSON_LEN: [u8; 4] = decode_json!(BitLength, br#"{ "len": "64" }"#, "len");
+
+    assert_eq!(u32::from_be_bytes(LEN), 3);
+    assert_eq!(LEN_WORDS, [3]);
+    assert_eq!(LENS, [[0, 0, 0, 1], [0, 0, 0, 32]]);
+    assert_eq!(JSON_LEN, [0, 0, 0, 8]);
+}
+
+#[test]
+fn reading_fields_from_rsp_file() {
+    const VECTORS: &[u8] = include_bytes!("sha256_short_msg.rsp");
+    const MSG: &[u8] = &decode_field!(Decoder::Hex, VECTORS, record = 3, "Msg");
+    const DIGEST: &[u8] =
+        &decode_field!(Decoder::Hex, VECTORS, section = "L = 32", record = 3, "MD");
+
+    assert_eq!(MSG, [0xb4, 0x19, 0x0e]);
+    assert_eq!(
+        DIGEST,
+        hex::decode("dff2e73091f6c05e528896c4c831b9448653dc2ff043528f6769437bc7b975c2").unwrap()
+    );
+}
+
+#[test]
+fn reading_values_from_json_file() {
+    const VECTORS: &[u8] = include_bytes!("eddsa_test.json");
+    const PUBLIC_KEY: &[u8] = &decode_json!(Decoder::Hex, VECTORS, "testGroups[1].publicKey.pk");
+    const MSG: &[u8] = &decode_json!(Decoder::Hex, VECTORS, "testGroups[1].tests[0].msg");
+    const EMPTY_MSG: &[u8] = &decode_json!(Decoder::Hex, VECTORS, "testGroups[0].tests[0].msg");
+    const SIG: &[u8] = &decode_json!(Decoder::Hex, VECTORS, "testGroups[1].tests[0].sig");
+
+    assert_eq!(
+        PUBLIC_KEY,
+        hex::decode("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c").unwrap()
+    );
+    assert_eq!(MSG, [0x72]);
+    assert!(EMPTY_MSG.is_empty());
+    assert_eq!(SIG.len(), 64);
+    assert_eq!(SIG[63], 0);
+}
+
 fn fuzz_hex_decoder<const N: usize>(samples: usize) {
     const CUSTOM_HEX: Decoder = Decoder::custom("0123456789abcdef");
 
@@ -121,3 +265,213 @@ fn bech32_decoder_mini_fuzz() {
     fuzz_bech32_decoder::<24>(10_000);
     fuzz_bech32_decoder::<64>(10_000);
 }
+
+#[cfg(feature = "serde")]
+mod serde_helpers {
+    use const_decoder::{serde::CustomEncoding, Encoding};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    struct Bech32Encoding;
+
+    impl CustomEncoding for Bech32Encoding {
+        const ENCODING: Encoding = Encoding::new("qpzry9x8gf2tvdw0s3jn54khce6mua7l");
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Arrays<const N: usize> {
+        #[serde(with = "const_decoder::serde::hex")]
+        hex: [u8; N],
+        #[serde(with = "const_decoder::serde::base64")]
+        base64: [u8; N],
+        #[serde(with = "const_decoder::serde::base64url")]
+        base64url: [u8; N],
+        #[serde(with = "const_decoder::serde::Custom::<Bech32Encoding>")]
+        bech32: [u8; N],
+    }
+
+    fn fuzz_serde<const N: usize>(samples: usize) {
+        let mut rng = rand::rng();
+        for _ in 0..samples {
+            let mut bytes = [0_u8; N];
+            rng.fill_bytes(&mut bytes);
+            let arrays = Arrays {
+                hex: bytes,
+                base64: bytes,
+                base64url: bytes,
+                bech32: bytes,
+            };
+
+            let json = serde_json::to_value(&arrays).unwrap();
+            assert_eq!(json["hex"], hex::encode(bytes));
+            assert_eq!(json["base64"], STANDARD.encode(bytes));
+            assert_eq!(json["base64url"], URL_SAFE_NO_PAD.encode(bytes));
+            let hrp = Hrp::parse("bc").unwrap();
+            let bech32 = bech32::encode::<Bech32>(hrp, &bytes).unwrap();
+            assert_eq!(json["bech32"], bech32[3..(bech32.len() - 6)]);
+
+            let restored: Arrays<N> = serde_json::from_value(json).unwrap();
+            assert_eq!(restored, arrays);
+        }
+    }
+
+    #[test]
+    fn serde_mini_fuzz() {
+        fuzz_serde::<1>(50);
+        fuzz_serde::<2>(100);
+        fuzz_serde::<3>(100);
+        fuzz_serde::<16>(1_000);
+        fuzz_serde::<33>(1_000);
+    }
+
+    #[test]
+    fn deserializing_accepts_same_inputs_as_decoder() {
+        let json = r#"{
+            "hex": "C0FFEE",
+            "base64": "wP/u",
+            "base64url": "wP_u",
+            "bech32": "crl7u"
+        }"#;
+        let arrays: Arrays<3> = serde_json::from_str(json).unwrap();
+        assert_eq!(arrays.hex, Decoder::Hex.decode(b"C0FFEE"));
+        assert_eq!(arrays.base64, arrays.hex);
+        assert_eq!(arrays.base64url, arrays.hex);
+        assert_eq!(arrays.bech32, arrays.hex);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Key {
+        #[serde(with = "const_decoder::serde::hex")]
+        #[allow(dead_code)] // only used for deserialization
+        key: [u8; 2],
+    }
+
+    #[test]
+    fn deserialization_errors() {
+        let err = serde_json::from_str::<Key>(r#"{ "key": "c0 ff" }"#).unwrap_err();
+        let err = err.to_string();
+        assert!(
+            err.contains("Character ' ' at position 2 is not a hex digit"),
+            "{err}"
+        );
+
+        let err = serde_json::from_str::<Key>(r#"{ "key": "c0ffee" }"#).unwrap_err();
+        let err = err.to_string();
+        assert!(
+            err.contains("invalid length 3, expected hex string encoding 2 bytes"),
+            "{err}"
+        );
+
+        let err = serde_json::from_str::<Key>(r#"{ "key": "c0fff" }"#).unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("Left-over state"), "{err}");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decoding_reader_for_large_input() {
+    use std::io::Read;
+
+    use const_decoder::DecodingReader;
+
+    let mut bytes = vec![0_u8; 100_000];
+    rand::rng().fill_bytes(&mut bytes);
+    let encoded = STANDARD.encode(&bytes);
+    let mut wrapped = Vec::with_capacity(encoded.len() * 77 / 76 + 1);
+    for line in encoded.as_bytes().chunks(76) {
+        wrapped.extend_from_slice(line);
+        wrapped.extend_from_slice(b"\r\n");
+    }
+
+    let decoder = Decoder::Base64.skip_whitespace().streaming();
+    let mut reader = DecodingReader::new(wrapped.as_slice(), decoder);
+    let mut decoded = vec![];
+    reader.read_to_end(&mut decoded).unwrap();
+    assert_eq!(decoded, bytes);
+
+    let pem_bundle = [include_str!("certificate.crt"); 3].join("\n");
+    let mut reader = DecodingReader::new(pem_bundle.as_bytes(), Pem::streaming());
+    let mut decoded = vec![];
+    reader.read_to_end(&mut decoded).unwrap();
+    let cert = pem::parse(include_bytes!("certificate.crt")).unwrap();
+    assert_eq!(decoded, [cert.contents(); 3].concat());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encoding_writer_mini_fuzz() {
+    use std::io::Write;
+
+    use const_decoder::{DecodingReader, Encoding, EncodingWriter};
+
+    let mut rng = rand::rng();
+    for _ in 0..1_000 {
+        let len = rng.random_range(0..1_000);
+        let mut bytes = vec![0_u8; len];
+        rng.fill_bytes(&mut bytes);
+
+        let mut writer = EncodingWriter::new(vec![], Encoding::HEX);
+        for chunk in bytes.chunks(7) {
+            writer.write_all(chunk).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), hex::encode(&bytes).into_bytes());
+
+        let mut writer = EncodingWriter::new(vec![], Encoding::BASE64_URL);
+        writer.write_all(&bytes).unwrap();
+        assert_eq!(
+            writer.finish().unwrap(),
+            URL_SAFE_NO_PAD.encode(&bytes).into_bytes()
+        );
+
+        let mut writer = EncodingWriter::mime(vec![]);
+        writer.write_all(&bytes).unwrap();
+        let encoded = writer.finish().unwrap();
+        let mut expected = String::new();
+        for line in STANDARD.encode(&bytes).as_bytes().chunks(76) {
+            expected += std::str::from_utf8(line).unwrap();
+            expected += "\r\n";
+        }
+        assert_eq!(String::from_utf8(encoded.clone()).unwrap(), expected);
+
+        let decoder = Decoder::Base64.skip_whitespace().streaming();
+        let mut decoded = vec![];
+        std::io::Read::read_to_end(
+            &mut DecodingReader::new(&encoded[..], decoder),
+            &mut decoded,
+        )
+        .unwrap();
+        assert_eq!(decoded, bytes);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encoding_writer_for_pem_file() {
+    use std::io::Write;
+
+    use const_decoder::EncodingWriter;
+
+    const CERT: &[u8] = &decode!(Pem, include_bytes!("certificate.crt"));
+
+    let mut writer = EncodingWriter::pem(vec![], "CERTIFICATE");
+    writer.write_all(CERT).unwrap();
+    let encoded = writer.finish().unwrap();
+
+    let expected = pem::Pem::new("CERTIFICATE", CERT);
+    let config = pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF);
+    assert_eq!(
+        String::from_utf8(encoded).unwrap(),
+        pem::encode_config(&expected, config)
+    );
+
+    let mut writer = EncodingWriter::pem(vec![], "CERTIFICATE").with_crlf();
+    writer.write_all(CERT).unwrap();
+    let encoded = writer.finish().unwrap();
+    let config = pem::EncodeConfig::new().set_line_ending(pem::LineEnding::CRLF);
+    assert_eq!(
+        String::from_utf8(encoded).unwrap(),
+        pem::encode_config(&expected, config)
+    );
+}